@@ -1,13 +1,69 @@
+use crate::class::ClassObject;
 use crate::object::Object;
 use crate::runtime_error::RuntimeError;
 use crate::Interpreter;
+use crate::Token;
 use std::fmt::Display;
+use std::ops::RangeInclusive;
 
 pub trait Callable: Display {
-    fn arity(&self) -> usize;
+    /// The numbers of arguments the callable accepts; for variadic callables the range
+    /// ends at `usize::MAX`.
+    fn arity(&self) -> RangeInclusive<usize>;
     fn call(
         &self,
         interpreter: &mut Interpreter,
         arguments: Vec<Object>,
     ) -> Result<Object, RuntimeError>;
+
+    /// Calls the callable with the positional and named arguments of a call expression
+    /// on `line`, after checking them.
+    ///
+    /// Only user-defined functions take named arguments, so by default any named
+    /// argument is an error.
+    fn call_with_arguments(
+        &self,
+        interpreter: &mut Interpreter,
+        line: usize,
+        arguments: Vec<Object>,
+        named_arguments: Vec<(Token, Object)>,
+    ) -> Result<Object, RuntimeError> {
+        if let Some((name, _)) = named_arguments.first() {
+            return Err(RuntimeError::argument_error(
+                name.line(),
+                format!("{} doesn't take named arguments.", self),
+            ));
+        }
+        check_arity(&self.arity(), arguments.len(), line)?;
+        self.call(interpreter, arguments)
+    }
+
+    /// Binds `this` to `class` when the callable is a static method looked up through
+    /// that class. Callables that don't use `this` return `None`.
+    fn bind_class(&self, _class: ClassObject) -> Result<Option<Box<dyn Callable>>, RuntimeError> {
+        Ok(None)
+    }
+}
+
+/// Returns an argument error if `count` arguments don't fit `arity`.
+pub fn check_arity(
+    arity: &RangeInclusive<usize>,
+    count: usize,
+    line: usize,
+) -> Result<(), RuntimeError> {
+    if arity.contains(&count) {
+        return Ok(());
+    }
+
+    let expected = if arity.start() == arity.end() {
+        arity.start().to_string()
+    } else if *arity.end() == usize::MAX {
+        format!("at least {}", arity.start())
+    } else {
+        format!("{} to {}", arity.start(), arity.end())
+    };
+    Err(RuntimeError::argument_error(
+        line,
+        format!("Expected {} arguments but got {}.", expected, count),
+    ))
 }
@@ -1,4 +1,4 @@
-use crate::callable::Callable;
+use crate::callable::{check_arity, Callable};
 
 use crate::function::Function;
 use crate::object::Object;
@@ -8,6 +8,7 @@ use crate::Token;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::ops::RangeInclusive;
 use std::rc::Rc;
 
 #[derive(Clone)]
@@ -15,8 +16,13 @@ pub struct ClassObject {
     pub name: String,
     pub superclass: Option<Box<Object>>,
     pub methods: HashMap<String, Function>,
+    /// The `set name(value)` accessors, which live apart from the methods because a
+    /// getter of the same name is stored with them.
+    pub setters: HashMap<String, Function>,
     #[allow(dead_code)]
     pub static_methods: HashMap<String, Rc<RefCell<Box<dyn Callable>>>>,
+    /// The `static var` fields, shared by every copy of the class value.
+    pub static_fields: Rc<RefCell<HashMap<String, Object>>>,
 }
 
 impl std::fmt::Debug for ClassObject {
@@ -25,7 +31,9 @@ impl std::fmt::Debug for ClassObject {
             .field("name", &self.name)
             .field("superclass", &self.superclass)
             .field("methods", &self.methods)
+            .field("setters", &self.setters)
             .field("static_methods", &"<dyn Callable map>")
+            .field("static_fields", &self.static_fields)
             .finish()
     }
 }
@@ -34,18 +42,23 @@ impl ClassObject {
     pub fn new(
         name: &str,
         superclass: Option<Box<Object>>,
-        methods: HashMap<String, Function>,
+        methods: Vec<Function>,
+        static_fields: HashMap<String, Object>,
     ) -> Self {
-        // Split methods into instance methods and static methods
+        // Split methods into instance methods, setters and static methods
         let mut instance_methods = HashMap::new();
+        let mut setters = HashMap::new();
         let mut static_methods = HashMap::new();
 
-        for (name, method) in methods {
+        for method in methods {
+            let name = method.declaration.name.lexeme().to_string();
             if method.declaration.is_static {
                 static_methods.insert(
                     name,
                     Rc::new(RefCell::new(Box::new(method) as Box<dyn Callable>)),
                 );
+            } else if method.declaration.is_setter {
+                setters.insert(name, method);
             } else {
                 instance_methods.insert(name, method);
             }
@@ -55,7 +68,9 @@ impl ClassObject {
             name: name.into(),
             superclass,
             methods: instance_methods,
+            setters,
             static_methods,
+            static_fields: Rc::new(RefCell::new(static_fields)),
         }
     }
 
@@ -74,7 +89,118 @@ impl ClassObject {
         }
     }
 
-    #[allow(dead_code)]
+    /// Returns whether both values are the same class.
+    ///
+    /// Classes are copied around by value, but the copies share their static fields.
+    pub fn ptr_eq(&self, other: &ClassObject) -> bool {
+        Rc::ptr_eq(&self.static_fields, &other.static_fields)
+    }
+
+    /// Reads a static field or static method, walking up the superclasses.
+    ///
+    /// Without a static member of that name, `name`, `superclass` and `methods()`
+    /// describe the class itself.
+    pub fn get_static(&self, name: &Token) -> Result<Object, RuntimeError> {
+        if let Some(fields) = self.find_static_fields(name.lexeme()) {
+            return Ok(fields.borrow()[name.lexeme()].clone());
+        }
+
+        if let Some(method) = self.find_bound_static_method(name.lexeme(), self)? {
+            return Ok(method);
+        }
+
+        match name.lexeme() {
+            "name" => Ok(Object::String(self.name.clone())),
+            "superclass" => Ok(self.superclass.as_deref().cloned().unwrap_or(Object::Nil)),
+            "methods" => Ok(Object::Callable(Rc::new(RefCell::new(
+                Box::new(MethodsFn(self.clone())) as Box<dyn Callable>,
+            )))),
+            _ => Err(RuntimeError::undefined_variable(
+                name.line(),
+                format!("Undefined static property '{}'.", name.lexeme()),
+            )),
+        }
+    }
+
+    /// Returns the sorted names of the public instance methods, including inherited ones.
+    pub fn method_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .methods
+            .keys()
+            .filter(|name| !name.contains('#'))
+            .cloned()
+            .collect();
+        if let Some(Object::Class(superclass)) = self.superclass.as_deref() {
+            names.extend(superclass.method_names());
+        }
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Assigns a static field.
+    ///
+    /// The field is changed on the class that declares it, which may be a superclass, or
+    /// created on this class if no class declares it.
+    pub fn set_static(&self, name: &Token, value: Object) {
+        let fields = self
+            .find_static_fields(name.lexeme())
+            .unwrap_or_else(|| Rc::clone(&self.static_fields));
+        fields.borrow_mut().insert(name.lexeme().to_string(), value);
+    }
+
+    /// Returns the static fields of the nearest class in the superclass chain that
+    /// declares `name`.
+    fn find_static_fields(&self, name: &str) -> Option<Rc<RefCell<HashMap<String, Object>>>> {
+        if self.static_fields.borrow().contains_key(name) {
+            return Some(Rc::clone(&self.static_fields));
+        }
+
+        match self.superclass.as_deref() {
+            Some(Object::Class(superclass)) => superclass.find_static_fields(name),
+            _ => None,
+        }
+    }
+
+    /// Looks up the setter for the property `name`, walking up the superclasses.
+    pub fn find_setter(&self, name: &str) -> Option<Function> {
+        if let Some(setter) = self.setters.get(name) {
+            return Some(setter.clone());
+        }
+
+        match self.superclass.as_deref() {
+            Some(Object::Class(superclass)) => superclass.find_setter(name),
+            _ => None,
+        }
+    }
+
+    /// Returns whether this class is `other` or one of its subclasses.
+    pub fn inherits_from(&self, other: &ClassObject) -> bool {
+        if self.ptr_eq(other) {
+            return true;
+        }
+
+        match self.superclass.as_deref() {
+            Some(Object::Class(superclass)) => superclass.inherits_from(other),
+            _ => false,
+        }
+    }
+
+    /// Looks up a static method, walking up the superclasses, and binds `this` in it to
+    /// `receiver`, the class the method is called through.
+    pub fn find_bound_static_method(
+        &self,
+        name: &str,
+        receiver: &ClassObject,
+    ) -> Result<Option<Object>, RuntimeError> {
+        let Some(method) = self.find_static_method(name) else {
+            return Ok(None);
+        };
+        let bound = method.borrow().bind_class(receiver.clone())?;
+        let method = bound.map_or(method, |bound| Rc::new(RefCell::new(bound)));
+        Ok(Some(Object::Callable(method)))
+    }
+
     pub fn find_static_method(&self, name: &str) -> Option<Rc<RefCell<Box<dyn Callable>>>> {
         if let Some(method) = self.static_methods.get(name) {
             return Some(method.clone());
@@ -93,11 +219,11 @@ impl ClassObject {
 
 // callable
 impl Callable for ClassObject {
-    fn arity(&self) -> usize {
+    fn arity(&self) -> RangeInclusive<usize> {
         if let Some(init) = self.find_method("init") {
             init.arity()
         } else {
-            0
+            0..=0
         }
     }
 
@@ -110,6 +236,51 @@ impl Callable for ClassObject {
 
         Ok(Object::ClassInstance(instance))
     }
+
+    fn call_with_arguments(
+        &self,
+        interp: &mut Interpreter,
+        line: usize,
+        args: Vec<Object>,
+        named_args: Vec<(Token, Object)>,
+    ) -> Result<Object, RuntimeError> {
+        let instance = ClassInstance::new(self.clone());
+
+        if let Some(init) = self.find_method("init") {
+            init.bind(instance.clone())?
+                .call_with_arguments(interp, line, args, named_args)?;
+        } else if let Some((name, _)) = named_args.first() {
+            return Err(RuntimeError::argument_error(
+                name.line(),
+                format!("{} doesn't take named arguments.", self),
+            ));
+        } else {
+            check_arity(&self.arity(), args.len(), line)?;
+        }
+
+        Ok(Object::ClassInstance(instance))
+    }
+}
+
+/// The `methods()` reflection method of a class.
+#[derive(Debug, Clone)]
+struct MethodsFn(ClassObject);
+
+impl Callable for MethodsFn {
+    fn call(&self, _interp: &mut Interpreter, _args: Vec<Object>) -> Result<Object, RuntimeError> {
+        let names = self.0.method_names().into_iter().map(Object::String);
+        Ok(Object::new_list(names.collect()))
+    }
+
+    fn arity(&self) -> RangeInclusive<usize> {
+        0..=0
+    }
+}
+
+impl Display for MethodsFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<native fn methods>")
+    }
 }
 
 impl Display for ClassObject {
@@ -118,6 +289,22 @@ impl Display for ClassObject {
     }
 }
 
+/// Returns the key the private member `#name` is stored under when it is accessed in the
+/// class declared by the token `class`.
+///
+/// The key is qualified with the token number of the declaration, which is unique even
+/// among classes of the same name, and keeps the class name for error messages: `#secret`
+/// in class `A` becomes `A@12#secret`.
+pub fn private_key(class: &Token, member: &str) -> String {
+    format!("{}@{}{}", class.lexeme(), class.token_number(), member)
+}
+
+/// Splits a key made by `private_key` into the class name and the `#name` of the member.
+fn split_private_key(key: &str) -> Option<(&str, &str)> {
+    let (class, rest) = key.split_once('@')?;
+    Some((class, &rest[rest.find('#')?..]))
+}
+
 #[derive(Clone, Debug)]
 pub struct ClassInstance {
     class: ClassObject,
@@ -132,31 +319,88 @@ impl ClassInstance {
         }
     }
 
-    pub fn get(&self, name: Token) -> Result<Object, RuntimeError> {
-        let fields = self.fields.borrow();
-        if fields.contains_key(name.lexeme()) {
-            Ok(fields.get(name.lexeme()).unwrap().clone())
-        } else {
-            // first try to find the method in the class before erroring
-            match self.class.find_method(name.lexeme()) {
-                Some(method) => match method.bind(self.clone()) {
-                    Ok(bound_method) => Ok(Object::Callable(Rc::new(RefCell::new(Box::new(
-                        bound_method,
-                    )
-                        as Box<dyn Callable>)))),
-                    Err(e) => Err(e),
+    /// Creates an instance whose fields are already set, for objects built by the interpreter.
+    pub fn with_fields(class: ClassObject, fields: HashMap<String, Object>) -> Self {
+        Self {
+            class,
+            fields: Rc::new(RefCell::new(fields)),
+        }
+    }
+
+    /// Returns the class the instance was created from.
+    pub fn class(&self) -> &ClassObject {
+        &self.class
+    }
+
+    /// Returns whether both values refer to the same instance.
+    pub fn ptr_eq(&self, other: &ClassInstance) -> bool {
+        Rc::ptr_eq(&self.fields, &other.fields)
+    }
+
+    /// Returns the value of a field, without looking at the methods of the class.
+    pub fn field(&self, name: &str) -> Option<Object> {
+        self.fields.borrow().get(name).cloned()
+    }
+
+    /// Reads a property: a getter is called, otherwise a field is returned or a method
+    /// bound to the instance.
+    pub fn get(&self, name: Token, interpreter: &mut Interpreter) -> Result<Object, RuntimeError> {
+        let method = self.class.find_method(name.lexeme());
+        if let Some(getter) = method
+            .as_ref()
+            .filter(|method| method.declaration.is_getter)
+        {
+            return getter.bind(self.clone())?.call(interpreter, Vec::new());
+        }
+
+        if let Some(value) = self.field(name.lexeme()) {
+            return Ok(value);
+        }
+
+        // first try to find the method in the class before erroring
+        match method {
+            Some(method) => Ok(Object::Callable(Rc::new(RefCell::new(Box::new(
+                method.bind(self.clone())?,
+            )
+                as Box<dyn Callable>)))),
+            None => Err(RuntimeError::undefined_variable(
+                name.line(),
+                match split_private_key(name.lexeme()) {
+                    Some((class, member)) => {
+                        format!("Class '{}' has no private member '{}'.", class, member)
+                    }
+                    None => format!("Undefined property '{}'.", name.lexeme()),
                 },
-                None => Err(RuntimeError::undefined_variable(
-                    name.line(),
-                    format!("Undefined property '{}'.", name.lexeme()),
-                )),
-            }
+            )),
         }
     }
 
-    pub fn set(&mut self, name: Token, value: Object) {
+    /// Writes a property: a setter is called, otherwise the field is set.
+    pub fn set(
+        &mut self,
+        name: Token,
+        value: Object,
+        interpreter: &mut Interpreter,
+    ) -> Result<(), RuntimeError> {
+        if let Some(setter) = self.class.find_setter(name.lexeme()) {
+            setter.bind(self.clone())?.call(interpreter, vec![value])?;
+            return Ok(());
+        }
+
+        if self
+            .class
+            .find_method(name.lexeme())
+            .is_some_and(|method| method.declaration.is_getter)
+        {
+            return Err(RuntimeError::type_error(
+                name.line(),
+                format!("Property '{}' has a getter but no setter.", name.lexeme()),
+            ));
+        }
+
         let mut fields = self.fields.borrow_mut();
         fields.insert(name.lexeme().into(), value);
+        Ok(())
     }
 }
 
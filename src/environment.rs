@@ -1,7 +1,7 @@
 use crate::object::Object;
 use crate::{runtime_error::RuntimeError, token::Token};
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
 /// Represents a variable environment (scope) for the interpreter.
@@ -13,6 +13,8 @@ pub struct Environment {
     pub enclosing: Option<Rc<RefCell<Environment>>>,
     /// Mapping of variable names to their values in the current scope
     pub values: HashMap<String, Object>,
+    /// Names of the variables in the current scope that were declared with `const`
+    pub constants: HashSet<String>,
 }
 
 impl Environment {
@@ -29,6 +31,7 @@ impl Environment {
         Self {
             values: HashMap::new(),
             enclosing: Some(enclosing),
+            constants: HashSet::new(),
         }
     }
 
@@ -41,6 +44,7 @@ impl Environment {
         Environment {
             enclosing: None,
             values: HashMap::new(),
+            constants: HashSet::new(),
         }
     }
 
@@ -68,6 +72,15 @@ impl Environment {
         }
     }
 
+    /// Defines a new constant in the current environment.
+    ///
+    /// Works like `define`, but later assignments to the name fail.
+    pub fn define_const(&mut self, name: String, value: Object) -> Result<(), RuntimeError> {
+        self.define(name.clone(), value)?;
+        self.constants.insert(name);
+        Ok(())
+    }
+
     /// Retrieves the value of a variable from the environment or its ancestors.
     ///
     /// If the variable is not found in the current environment,
@@ -195,7 +208,8 @@ impl Environment {
     /// Assigns a new value to an existing variable in the environment chain.
     ///
     /// The assignment succeeds only if the variable already exists
-    /// in the current environment or any parent environment.
+    /// in the current environment or any parent environment, and was
+    /// not declared as a constant.
     ///
     /// # Arguments
     ///
@@ -207,7 +221,12 @@ impl Environment {
     /// `Ok(())` if the assignment was successful,
     /// or a RuntimeError if the variable is not defined in any accessible scope
     pub fn assign(&mut self, name: &Token, value: &Object) -> Result<(), RuntimeError> {
-        if self.values.contains_key(name.lexeme()) {
+        if self.constants.contains(name.lexeme()) {
+            Err(RuntimeError::other(
+                name.line(),
+                format!("Cannot assign to constant '{}'.", name.lexeme()),
+            ))
+        } else if self.values.contains_key(name.lexeme()) {
             self.values.insert(name.lexeme().to_owned(), value.clone());
             Ok(())
         } else if let Some(ref parent) = self.enclosing {
@@ -1,4 +1,5 @@
 use crate::runtime_error::RuntimeError;
+use crate::stmt_types::FunctionStmt;
 use crate::Token;
 
 /// Represents the different types of expressions in the abstract syntax tree (AST).
@@ -23,6 +24,22 @@ pub enum Expr {
     PropertyAssignment(PropertyAssignmentExpr),
     This(ThisExpr),
     Super(SuperExpr),
+    /// Represents a list literal (e.g., `[1, 2, 3]`).
+    List(ListExpr),
+    /// Represents an index access (e.g., `xs[0]`).
+    Index(IndexExpr),
+    /// Represents an assignment to an index (e.g., `xs[0] = 1`).
+    IndexAssignment(IndexAssignmentExpr),
+    /// Represents a map literal (e.g., `{"a": 1, "b": 2}`).
+    Map(MapExpr),
+    /// Represents an anonymous function (e.g., `fn (x) { return x; }` or `|x| x * 2`).
+    Lambda(LambdaExpr),
+    /// Represents an increment or decrement (e.g., `++i`, `xs[0]--`).
+    Update(UpdateExpr),
+    /// Represents a conditional expression (e.g., `cond ? a : b`).
+    Ternary(TernaryExpr),
+    /// Represents an interpolated string (e.g., `"Hello ${name}"`).
+    Interpolation(InterpolationExpr),
 }
 
 /// Defines the visitor trait for traversing the `Expr` abstract syntax tree.
@@ -111,6 +128,18 @@ pub trait ExprVisitor<T> {
     ) -> Result<T, RuntimeError>;
     fn visit_this_expr(&mut self, expr: &mut ThisExpr) -> Result<T, RuntimeError>;
     fn visit_super_expr(&mut self, expr: &mut SuperExpr) -> Result<T, RuntimeError>;
+    fn visit_list_expr(&mut self, expr: &mut ListExpr) -> Result<T, RuntimeError>;
+    fn visit_index_expr(&mut self, expr: &mut IndexExpr) -> Result<T, RuntimeError>;
+    fn visit_index_assignment_expr(
+        &mut self,
+        expr: &mut IndexAssignmentExpr,
+    ) -> Result<T, RuntimeError>;
+    fn visit_map_expr(&mut self, expr: &mut MapExpr) -> Result<T, RuntimeError>;
+    fn visit_lambda_expr(&mut self, expr: &mut LambdaExpr) -> Result<T, RuntimeError>;
+    fn visit_update_expr(&mut self, expr: &mut UpdateExpr) -> Result<T, RuntimeError>;
+    fn visit_ternary_expr(&mut self, expr: &mut TernaryExpr) -> Result<T, RuntimeError>;
+    fn visit_interpolation_expr(&mut self, expr: &mut InterpolationExpr)
+        -> Result<T, RuntimeError>;
 }
 
 impl Expr {
@@ -144,6 +173,14 @@ impl Expr {
             Expr::PropertyAssignment(expr) => visitor.visit_property_assignment_expr(expr),
             Expr::This(expr) => visitor.visit_this_expr(expr),
             Expr::Super(expr) => visitor.visit_super_expr(expr),
+            Expr::List(expr) => visitor.visit_list_expr(expr),
+            Expr::Index(expr) => visitor.visit_index_expr(expr),
+            Expr::IndexAssignment(expr) => visitor.visit_index_assignment_expr(expr),
+            Expr::Map(expr) => visitor.visit_map_expr(expr),
+            Expr::Lambda(expr) => visitor.visit_lambda_expr(expr),
+            Expr::Update(expr) => visitor.visit_update_expr(expr),
+            Expr::Ternary(expr) => visitor.visit_ternary_expr(expr),
+            Expr::Interpolation(expr) => visitor.visit_interpolation_expr(expr),
         }
     }
 }
@@ -196,6 +233,8 @@ pub struct VariableExpr {
 pub struct AssignExpr {
     pub name: Token,
     pub value: Box<Expr>,
+    /// The binary operator of a compound assignment (`+` for `+=`), `None` for `=`.
+    pub operator: Option<Token>,
 }
 
 #[derive(Debug, Clone)]
@@ -210,12 +249,17 @@ pub struct CallExpr {
     pub callee: Box<Expr>,
     pub paren: Token,
     pub arguments: Vec<Expr>,
+    /// The `name: value` arguments, which always come after the positional ones.
+    pub named_arguments: Vec<(Token, Expr)>,
 }
 
 #[derive(Debug, Clone)]
 pub struct PropertyAccessExpr {
     pub object: Box<Expr>,
     pub name: Token,
+    /// Whether this is an optional access (`object?.name`), which evaluates to nil,
+    /// together with the rest of the call chain, when the object is nil.
+    pub optional: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -223,6 +267,8 @@ pub struct PropertyAssignmentExpr {
     pub object: Box<Expr>,
     pub name: Token,
     pub value: Box<Expr>,
+    /// The binary operator of a compound assignment (`+` for `+=`), `None` for `=`.
+    pub operator: Option<Token>,
 }
 
 #[derive(Debug, Clone)]
@@ -236,6 +282,86 @@ pub struct SuperExpr {
     pub method: Token,
 }
 
+/// Represents a list literal in the AST.
+#[derive(Debug, Clone)]
+pub struct ListExpr {
+    /// The opening bracket token, used for error reporting.
+    pub bracket: Token,
+    /// The expressions producing the elements of the list.
+    pub elements: Vec<Expr>,
+}
+
+/// Represents an index access (`object[index]`) in the AST.
+#[derive(Debug, Clone)]
+pub struct IndexExpr {
+    pub object: Box<Expr>,
+    /// The closing bracket token, used for error reporting.
+    pub bracket: Token,
+    pub index: Box<Expr>,
+}
+
+/// Represents an assignment to an index (`object[index] = value`) in the AST.
+#[derive(Debug, Clone)]
+pub struct IndexAssignmentExpr {
+    pub object: Box<Expr>,
+    /// The closing bracket token, used for error reporting.
+    pub bracket: Token,
+    pub index: Box<Expr>,
+    pub value: Box<Expr>,
+    /// The binary operator of a compound assignment (`+` for `+=`), `None` for `=`.
+    pub operator: Option<Token>,
+}
+
+/// Represents a map literal in the AST.
+#[derive(Debug, Clone)]
+pub struct MapExpr {
+    /// The opening brace token, used for error reporting.
+    pub brace: Token,
+    /// The key and value expressions of each entry, in source order.
+    pub entries: Vec<(Expr, Expr)>,
+}
+
+/// Represents an anonymous function expression in the AST.
+#[derive(Debug, Clone)]
+pub struct LambdaExpr {
+    /// The function itself; its name is a synthesized `lambda` token located at
+    /// the `fn` or `|` that introduced the expression.
+    pub declaration: FunctionStmt,
+}
+
+/// Represents a prefix or postfix increment/decrement in the AST.
+#[derive(Debug, Clone)]
+pub struct UpdateExpr {
+    /// The updated variable, property or index expression.
+    pub target: Box<Expr>,
+    /// The `++` or `--` token.
+    pub operator: Token,
+    /// Whether the operator precedes the target, which makes the expression
+    /// evaluate to the updated value instead of the previous one.
+    pub prefix: bool,
+}
+
+/// Represents a conditional (`condition ? then_branch : else_branch`) expression in the AST.
+#[derive(Debug, Clone)]
+pub struct TernaryExpr {
+    pub condition: Box<Expr>,
+    /// The `?` token, used for error reporting.
+    pub question: Token,
+    pub then_branch: Box<Expr>,
+    pub else_branch: Box<Expr>,
+}
+
+/// Represents an interpolated string in the AST.
+///
+/// The parts alternate between string literals and embedded expressions, starting and
+/// ending with a literal; their displayed values are concatenated.
+#[derive(Debug, Clone)]
+pub struct InterpolationExpr {
+    /// The token of the first string segment, used for error reporting.
+    pub token: Token,
+    pub parts: Vec<Expr>,
+}
+
 use std::hash::{Hash, Hasher};
 
 impl PartialEq for Expr {
@@ -254,7 +380,11 @@ impl PartialEq for Expr {
                 a.name.literal() == b.name.literal()
                     && a.name.token_number() == b.name.token_number()
             }
-            (Assign(a), Assign(b)) => a.name.literal() == b.name.literal() && a.value == b.value,
+            (Assign(a), Assign(b)) => {
+                a.name.lexeme() == b.name.lexeme()
+                    && a.name.token_number() == b.name.token_number()
+                    && a.value == b.value
+            }
             (Logical(a), Logical(b)) => {
                 a.left == b.left && a.right == b.right && a.operator.lexeme() == b.operator.lexeme()
             }
@@ -264,6 +394,10 @@ impl PartialEq for Expr {
                         .iter()
                         .zip(b.arguments.clone())
                         .all(|(a_e, b_e)| *a_e == b_e)
+                    && a.named_arguments
+                        .iter()
+                        .zip(&b.named_arguments)
+                        .all(|((a_n, a_e), (b_n, b_e))| a_n.lexeme() == b_n.lexeme() && a_e == b_e)
             }
             (PropertyAssignment(a), PropertyAssignment(b)) => a.name.lexeme() == b.name.lexeme(),
             (PropertyAccess(a), PropertyAccess(b)) => a.name.lexeme() == b.name.lexeme(),
@@ -280,6 +414,39 @@ impl PartialEq for Expr {
                     && a.method.lexeme() == b.method.lexeme()
                     && a.method.token_number() == b.method.token_number()
             }
+            (List(a), List(b)) => {
+                a.bracket.token_number() == b.bracket.token_number() && a.elements == b.elements
+            }
+            (Index(a), Index(b)) => {
+                a.bracket.token_number() == b.bracket.token_number()
+                    && a.object == b.object
+                    && a.index == b.index
+            }
+            (IndexAssignment(a), IndexAssignment(b)) => {
+                a.bracket.token_number() == b.bracket.token_number()
+                    && a.object == b.object
+                    && a.index == b.index
+                    && a.value == b.value
+            }
+            (Map(a), Map(b)) => {
+                a.brace.token_number() == b.brace.token_number() && a.entries == b.entries
+            }
+            (Ternary(a), Ternary(b)) => {
+                a.question.token_number() == b.question.token_number()
+                    && a.condition == b.condition
+                    && a.then_branch == b.then_branch
+                    && a.else_branch == b.else_branch
+            }
+            (Interpolation(a), Interpolation(b)) => {
+                a.token.token_number() == b.token.token_number() && a.parts == b.parts
+            }
+            (Update(a), Update(b)) => {
+                a.operator.token_number() == b.operator.token_number() && a.target == b.target
+            }
+            (Lambda(a), Lambda(b)) => {
+                a.declaration.name.token_number() == b.declaration.name.token_number()
+                    && a.declaration.name.line() == b.declaration.name.line()
+            }
             _ => false,
         }
     }
@@ -316,6 +483,7 @@ impl Hash for Expr {
             }
             Expr::Assign(expr) => {
                 expr.name.lexeme().hash(state);
+                expr.name.token_number().hash(state);
             }
             Expr::Logical(expr) => {
                 expr.left.hash(state);
@@ -327,6 +495,10 @@ impl Hash for Expr {
                 for arg in &expr.arguments {
                     arg.hash(state);
                 }
+                for (name, arg) in &expr.named_arguments {
+                    name.lexeme().hash(state);
+                    arg.hash(state);
+                }
             }
             Expr::PropertyAccess(expr) => {
                 expr.name.token_number().hash(state);
@@ -354,6 +526,48 @@ impl Hash for Expr {
                 expr.method.line().hash(state);
                 expr.method.lexeme().hash(state);
             }
+            Expr::List(expr) => {
+                expr.bracket.token_number().hash(state);
+                for element in &expr.elements {
+                    element.hash(state);
+                }
+            }
+            Expr::Index(expr) => {
+                expr.bracket.token_number().hash(state);
+                expr.object.hash(state);
+                expr.index.hash(state);
+            }
+            Expr::IndexAssignment(expr) => {
+                expr.bracket.token_number().hash(state);
+                expr.object.hash(state);
+                expr.index.hash(state);
+                expr.value.hash(state);
+            }
+            Expr::Map(expr) => {
+                expr.brace.token_number().hash(state);
+                for (key, value) in &expr.entries {
+                    key.hash(state);
+                    value.hash(state);
+                }
+            }
+            Expr::Ternary(expr) => {
+                expr.question.token_number().hash(state);
+                expr.condition.hash(state);
+                expr.then_branch.hash(state);
+                expr.else_branch.hash(state);
+            }
+            Expr::Interpolation(expr) => {
+                expr.token.token_number().hash(state);
+                expr.parts.hash(state);
+            }
+            Expr::Update(expr) => {
+                expr.operator.token_number().hash(state);
+                expr.target.hash(state);
+            }
+            Expr::Lambda(expr) => {
+                expr.declaration.name.token_number().hash(state);
+                expr.declaration.name.line().hash(state);
+            }
         }
     }
 }
@@ -1,13 +1,15 @@
-use crate::callable::Callable;
+use crate::callable::{check_arity, Callable};
+use std::ops::RangeInclusive;
 use std::rc::Rc;
 use std::{cell::RefCell, fmt::Display};
 
 use crate::{
-    class::ClassInstance,
+    class::{ClassInstance, ClassObject},
     environment::Environment,
     object::Object,
     runtime_error::RuntimeError,
     stmt_types::{FunctionStmt, StmtVisitor},
+    Token,
 };
 
 /// Represents a user-defined function in the language.
@@ -40,8 +42,13 @@ impl Function {
     }
 
     pub fn bind(&self, instance: ClassInstance) -> Result<Function, RuntimeError> {
+        self.bind_this(Object::ClassInstance(instance))
+    }
+
+    /// Returns a copy of the function in which `this` refers to `this`.
+    fn bind_this(&self, this: Object) -> Result<Function, RuntimeError> {
         let mut environment = Environment::new_enclosed(self.closure.clone());
-        environment.define("this".to_string(), Object::ClassInstance(instance))?;
+        environment.define("this".to_string(), this)?;
         Ok(Function {
             declaration: self.declaration.clone(),
             closure: Rc::new(RefCell::new(environment)),
@@ -66,31 +73,42 @@ impl Callable for Function {
         interpreter: &mut crate::interpreter::Interpreter,
         arguments: Vec<Object>,
     ) -> Result<Object, RuntimeError> {
-        let mut environment = Environment::new_enclosed(self.closure.clone());
-
-        for i in 0..self.declaration.params.len() {
-            environment.define(
-                self.declaration.params.get(i).unwrap().lexeme().to_string(),
-                arguments.get(i).unwrap().clone(),
-            )?;
-        }
+        self.call_with_arguments(
+            interpreter,
+            self.declaration.name.line(),
+            arguments,
+            Vec::new(),
+        )
+    }
 
-        // save the current environment
-        let previous = interpreter.environment.clone();
+    fn call_with_arguments(
+        &self,
+        interpreter: &mut crate::interpreter::Interpreter,
+        line: usize,
+        arguments: Vec<Object>,
+        named_arguments: Vec<(Token, Object)>,
+    ) -> Result<Object, RuntimeError> {
+        let environment = Rc::new(RefCell::new(Environment::new_enclosed(
+            self.closure.clone(),
+        )));
 
-        // replace interpreter's environment with new one
-        interpreter.environment = Rc::new(environment.into());
+        // replace interpreter's environment with new one, default values are evaluated in it
+        let previous = std::mem::replace(&mut interpreter.environment, environment);
 
         let mut body = self.declaration.body.clone();
-        let return_val = match interpreter.visit_block_stmt(&mut body) {
+        let result = self
+            .bind_arguments(interpreter, line, arguments, named_arguments)
+            .and_then(|_| interpreter.visit_block_stmt(&mut body));
+
+        // restore previous environment
+        interpreter.environment = previous;
+
+        let return_val = match result {
             Ok(()) => Object::Nil,
             Err(RuntimeError::Return(value)) => value.unwrap_or(Object::Nil),
             Err(e) => return Err(e),
         };
 
-        // restore previous environment
-        interpreter.environment = previous;
-
         if self.is_initializer {
             return self.closure.borrow().get_at(&0, "this".to_string());
         }
@@ -98,9 +116,93 @@ impl Callable for Function {
         Ok(return_val)
     }
 
-    /// Returns the number of parameters the function expects
-    fn arity(&self) -> usize {
-        self.declaration.params.len()
+    /// Static methods are bound to the class they are called through, so `this` in a
+    /// static method inherited by a subclass is the subclass.
+    fn bind_class(&self, class: ClassObject) -> Result<Option<Box<dyn Callable>>, RuntimeError> {
+        Ok(Some(Box::new(self.bind_this(Object::Class(class))?)))
+    }
+
+    /// Returns the range of positional argument counts the function accepts
+    fn arity(&self) -> RangeInclusive<usize> {
+        let params = &self.declaration.params;
+        let required = params
+            .iter()
+            .filter(|param| param.default.is_none() && !param.variadic)
+            .count();
+
+        if params.last().is_some_and(|param| param.variadic) {
+            required..=usize::MAX
+        } else {
+            required..=params.len()
+        }
+    }
+}
+
+impl Function {
+    /// Defines the parameters of the function in the interpreter's current environment.
+    ///
+    /// Positional arguments are assigned in order, then named arguments by name, and any
+    /// parameter left over gets its default value. A variadic parameter receives a list
+    /// of the remaining positional arguments.
+    fn bind_arguments(
+        &self,
+        interpreter: &mut crate::interpreter::Interpreter,
+        line: usize,
+        arguments: Vec<Object>,
+        mut named_arguments: Vec<(Token, Object)>,
+    ) -> Result<(), RuntimeError> {
+        let arity = self.arity();
+        if arguments.len() > *arity.end() {
+            check_arity(&arity, arguments.len(), line)?;
+        }
+
+        let params = &self.declaration.params;
+        for (index, (name, _)) in named_arguments.iter().enumerate() {
+            let position = params
+                .iter()
+                .position(|param| param.name.lexeme() == name.lexeme() && !param.variadic);
+            let message = match position {
+                None => format!("{} has no parameter named '{}'.", self, name.lexeme()),
+                Some(position)
+                    if position < arguments.len()
+                        || named_arguments[..index]
+                            .iter()
+                            .any(|(other, _)| other.lexeme() == name.lexeme()) =>
+                {
+                    format!("Got multiple values for parameter '{}'.", name.lexeme())
+                }
+                Some(_) => continue,
+            };
+            return Err(RuntimeError::argument_error(name.line(), message));
+        }
+
+        let mut arguments = arguments.into_iter();
+        for param in params {
+            let named = named_arguments
+                .iter()
+                .position(|(name, _)| name.lexeme() == param.name.lexeme());
+
+            let value = if param.variadic {
+                Object::List(Rc::new(RefCell::new(arguments.by_ref().collect())))
+            } else if let Some(value) = arguments.next() {
+                value
+            } else if let Some(index) = named {
+                named_arguments.swap_remove(index).1
+            } else if let Some(default) = &param.default {
+                default.clone().accept(interpreter)?
+            } else {
+                return Err(RuntimeError::argument_error(
+                    line,
+                    format!("Missing argument for parameter '{}'.", param.name.lexeme()),
+                ));
+            };
+
+            interpreter
+                .environment
+                .borrow_mut()
+                .define(param.name.lexeme().to_string(), value)?;
+        }
+        Ok(())
     }
 }
 
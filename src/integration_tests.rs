@@ -556,4 +556,2281 @@ mod test {
 
         assert_eq!(result, "314.159\n");
     }
+
+    #[test]
+    fn test_lists() {
+        let source = r#"
+        var xs = [1, "two", [3]];
+        print xs;
+        print xs[1];
+        print xs[2][0];
+
+        xs[0] = xs[0] + 10;
+        print xs;
+
+        var alias = xs;
+        alias[1] = nil;
+        print xs[1];
+        print [] == [];
+        print "abc"[2];
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result,
+            "[1, two, [3]]\ntwo\n3\n[11, two, [3]]\nNil\ntrue\nc\n"
+        );
+    }
+
+    #[test]
+    fn test_maps() {
+        let source = r#"
+        var m = {"a": 1, 2: "two", true: nil,};
+        print m["a"];
+        print m[2];
+        print m[true];
+
+        m["a"] = m["a"] + 1;
+        m["b"] = [1];
+        print m["a"];
+        print m["b"];
+
+        var single = {"only": 1};
+        print single;
+        print {} == {};
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(result, "1\ntwo\nNil\n2\n[1]\n{only: 1}\ntrue\n");
+    }
+
+    #[test]
+    fn test_map_missing_key() {
+        let source = r#"
+        var m = {"a": 1};
+        print m["b"];
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let mut interpreter = Interpreter::new_with_output(output);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        assert!(interpreter.interprete(&mut stmts).is_err());
+    }
+
+    #[test]
+    fn test_modulo_floor_division_and_exponent() {
+        let source = r#"
+        print 7 % 3;
+        print -7 % 3;
+        print 7 % -3;
+        print 7 ~/ 2;
+        print -7 ~/ 2;
+        print 2 ** 10;
+        print 2 ** 3 ** 2;
+        print -2 ** 2;
+        print 2 ** -1;
+        print 1 + 2 * 3 % 4;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(result, "1\n2\n-2\n3\n-4\n1024\n512\n-4\n0.5\n3\n");
+    }
+
+    #[test]
+    fn test_modulo_and_floor_division_by_zero() {
+        for source in ["print 1 % 0;", "print 1 ~/ 0;"] {
+            let tokens = tokenize(source).expect("Tokenization failed");
+            let mut stmts = parse(tokens).expect("Parsing failed");
+
+            let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+            let mut interpreter = Interpreter::new_with_output(output);
+            let mut resolver = Resolver::new(&mut interpreter);
+            resolver.resolve(&mut stmts).expect("Resolving failed");
+
+            let error = interpreter.interprete(&mut stmts).unwrap_err();
+            assert!(error.to_string().contains("Division by zero"), "{}", error);
+        }
+    }
+
+    #[test]
+    fn test_bitwise_operators() {
+        let source = r#"
+        print 12 & 10;
+        print 12 | 10;
+        print 12 ^ 10;
+        print ~5;
+        print 1 << 4;
+        print -16 >> 2;
+        print 1 | 2 ^ 3 & 4;
+        print 6 & 3 == 2;
+        print 1 + 1 << 2;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(result, "8\n14\n6\n-6\n16\n-4\n3\ntrue\n8\n");
+    }
+
+    #[test]
+    fn test_bitwise_operators_reject_fractions() {
+        for source in [
+            "print 1.5 & 1;",
+            "print ~0.5;",
+            "print 1 << 64;",
+            "print \"a\" | 1;",
+        ] {
+            let tokens = tokenize(source).expect("Tokenization failed");
+            let mut stmts = parse(tokens).expect("Parsing failed");
+
+            let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+            let mut interpreter = Interpreter::new_with_output(output);
+            let mut resolver = Resolver::new(&mut interpreter);
+            resolver.resolve(&mut stmts).expect("Resolving failed");
+
+            assert!(interpreter.interprete(&mut stmts).is_err(), "{}", source);
+        }
+    }
+
+    #[test]
+    fn test_integers() {
+        let source = r#"
+        print 9007199254740993 + 0;
+        print 7 ~/ 2;
+        print 7 / 2;
+        print 1 + 0.5;
+        print 2 ** 62;
+        print 1 == 1.0;
+        print 2 < 2.5;
+        var m = {1: "one"};
+        print m[1.0];
+        print [10, 20][1.0];
+        print Math.int(-3.9);
+        print Math.int("42") + 1;
+        print Math.float(3) / 2;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result,
+            "9007199254740993\n3\n3.5\n1.5\n4611686018427387904\ntrue\ntrue\none\n20\n-3\n43\n1.5\n"
+        );
+    }
+
+    #[test]
+    fn test_integer_overflow() {
+        for source in [
+            "print 9223372036854775807 + 1;",
+            "print -9223372036854775807 - 2;",
+            "print 4294967296 * 4294967296;",
+            "print 2 ** 63;",
+        ] {
+            let tokens = tokenize(source).expect("Tokenization failed");
+            let mut stmts = parse(tokens).expect("Parsing failed");
+
+            let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+            let mut interpreter = Interpreter::new_with_output(output);
+            let mut resolver = Resolver::new(&mut interpreter);
+            resolver.resolve(&mut stmts).expect("Resolving failed");
+
+            let error = interpreter.interprete(&mut stmts).unwrap_err();
+            assert!(error.to_string().contains("Integer overflow"), "{}", error);
+        }
+    }
+
+    #[test]
+    fn test_lambdas() {
+        let source = r#"
+        fn apply(f, value) {
+            return f(value);
+        }
+
+        fn make_adder(n) {
+            return |x| x + n;
+        }
+
+        print apply(fn (x) { return x * 3; }, 2);
+        print apply(|x| x * 2, 21);
+        print make_adder(10)(5);
+        print (|| "no params")();
+        print (|a, b| { var sum = a + b; return sum; })(1, 2);
+
+        var counter = 0;
+        var increment = fn () { counter = counter + 1; };
+        increment();
+        increment();
+        print counter;
+
+        var fns = [|x| x + 1, |x| x - 1];
+        print fns[1](10);
+        print |x| x;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(result, "6\n42\n15\nno params\n3\n2\n9\n<fn lambda>\n");
+    }
+
+    #[test]
+    fn test_compound_assignment_and_increment() {
+        let source = r#"
+        var total = 0;
+        for (var i = 0; i < 5; i++) {
+            total += i;
+        }
+        print total;
+
+        var x = 10;
+        x -= 3;
+        x *= 2;
+        x /= 4;
+        print x;
+        x = 7;
+        x %= 4;
+        print x;
+
+        var s = "a";
+        s += "b";
+        print s;
+
+        var n = 5;
+        print n++;
+        print n;
+        print ++n;
+        print n--;
+        print --n;
+
+        class Counter {
+            fn init() {
+                this.count = 0;
+            }
+            fn bump() {
+                this.count += 1;
+                return this.count++;
+            }
+        }
+        var c = Counter();
+        print c.bump();
+        print c.count;
+
+        var calls = 0;
+        var xs = [1, 2];
+        fn get() {
+            calls++;
+            return xs;
+        }
+        get()[0] += 10;
+        get()[1]++;
+        print xs;
+        print calls;
+
+        var m = {"hits": 1};
+        m["hits"] *= 3;
+        print m["hits"];
+
+        {
+            var depth = 1;
+            {
+                depth += 1;
+                fn inner() {
+                    depth += 1;
+                }
+                inner();
+            }
+            print depth;
+        }
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result,
+            "10\n3.5\n3\nab\n5\n6\n7\n7\n5\n1\n2\n[11, 3]\n2\n3\n3\n"
+        );
+    }
+
+    #[test]
+    fn test_increment_requires_number() {
+        let source = r#"
+        var s = "a";
+        s++;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let mut interpreter = Interpreter::new_with_output(output);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        assert!(interpreter.interprete(&mut stmts).is_err());
+        assert!(parse(tokenize("1++;").unwrap()).is_err());
+        for source in ["a?.b = 1;", "a?.b++;", "--a?.b;"] {
+            let error = parse(tokenize(source).unwrap()).unwrap_err();
+            assert!(error[0].to_string().contains("Invalid assignment target."));
+        }
+    }
+
+    #[test]
+    fn test_ternary_nil_coalescing_and_optional_chaining() {
+        let source = r#"
+        print true ? "yes" : "no";
+        print nil ? "yes" : "no";
+        var n = 5;
+        print n > 3 ? n < 10 ? "mid" : "big" : "small";
+        var picked = false ? 1 : 2;
+        print picked;
+
+        print nil ?? "default";
+        print false ?? "default";
+        print nil ?? nil ?? 3;
+        print 0 ?? 1 + 1;
+
+        class Node {
+            fn init(value, next) {
+                this.value = value;
+                this.next = next;
+            }
+            fn describe() {
+                return "node " + this.value;
+            }
+        }
+        var list = Node(1, Node(2, nil));
+        print list?.next?.value;
+        print list.next.next?.value;
+        print list.next.next?.next.value;
+        print list.next.next?.describe();
+        print list?.describe();
+        var missing = nil;
+        print missing?.value ?? "empty";
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result,
+            "yes\nno\nmid\n2\ndefault\nfalse\n3\n0\n2\nNil\nNil\nNil\nnode 1\nempty\n"
+        );
+    }
+
+    #[test]
+    fn test_optional_chaining_only_guards_nil() {
+        let source = r#"
+        var grouped = nil;
+        print (grouped?.value).other;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let mut interpreter = Interpreter::new_with_output(output);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        // parentheses end the short-circuited chain
+        assert!(interpreter.interprete(&mut stmts).is_err());
+        assert!(parse(tokenize("var a = nil; a?.b = 1;").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_modules() {
+        let dir = std::env::temp_dir().join("bucceolang_test_modules");
+        std::fs::create_dir_all(dir.join("lib")).unwrap();
+        std::fs::write(
+            dir.join("lib/helper.bl"),
+            r#"
+            print "loading helper";
+            var name = "helper";
+            fn twice(x) { return x * 2; }
+            "#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("lib/utils.bl"),
+            r#"
+            import "helper.bl";
+            var counter = 0;
+            fn double(x) {
+                counter += 1;
+                return helper.twice(x);
+            }
+            fn count() { return counter; }
+            "#,
+        )
+        .unwrap();
+
+        let source = r#"
+        import "lib/utils.bl";
+        import "lib/utils.bl" as u;
+        import "lib/helper.bl" as h;
+        print utils.double(21);
+        print u.count();
+        print h.name;
+        var counter = 100;
+        print utils.count();
+        print utils;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        interpreter
+            .loading
+            .push(dir.canonicalize().unwrap().join("main.bl"));
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        // each module is loaded once and keeps its own globals
+        assert_eq!(result, "loading helper\n42\n1\nhelper\n1\n<module utils>\n");
+    }
+
+    #[test]
+    fn test_module_errors() {
+        let dir = std::env::temp_dir().join("bucceolang_test_module_errors");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.bl"), r#"import "b.bl";"#).unwrap();
+        std::fs::write(dir.join("b.bl"), r#"import "a.bl";"#).unwrap();
+        std::fs::write(dir.join("c.bl"), "var x = 1;").unwrap();
+        std::fs::write(dir.join("d.bl"), "var x = 1;\nprint (x;").unwrap();
+
+        let run = |source: &str| {
+            let tokens = tokenize(source).expect("Tokenization failed");
+            let mut stmts = parse(tokens).expect("Parsing failed");
+
+            let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+            let mut interpreter = Interpreter::new_with_output(output);
+            interpreter
+                .loading
+                .push(dir.canonicalize().unwrap().join("main.bl"));
+            let mut resolver = Resolver::new(&mut interpreter);
+            resolver.resolve(&mut stmts).expect("Resolving failed");
+            interpreter.interprete(&mut stmts)
+        };
+
+        let cycle = run(r#"import "a.bl";"#).unwrap_err();
+        assert!(cycle.to_string().contains("Import cycle detected"));
+        assert!(run(r#"import "missing.bl";"#).is_err());
+        assert!(run(r#"import "c.bl"; print c.y;"#).is_err());
+        // only the names defined by the module are exported
+        assert!(run(r#"import "c.bl"; print c.Math;"#).is_err());
+        assert!(run(r#"import "c.bl"; print c.Error;"#).is_err());
+        let parse_error = run(r#"import "d.bl";"#).unwrap_err().to_string();
+        assert!(parse_error.contains("d.bl: [line 2] Error at 'Semicolon ;'"));
+        assert!(parse(tokenize(r#"import "my-lib.bl";"#).unwrap()).is_err());
+        assert!(parse(tokenize(r#"import "my-lib.bl" as lib;"#).unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_try_catch_finally() {
+        let source = r#"
+        fn divide(a, b) { return a / b; }
+        try {
+            divide(1, 0);
+        } catch (e) {
+            print e.kind;
+            print e.message;
+            print e.line;
+        } finally {
+            print "cleanup";
+        }
+        class ValidationError {
+            fn init(message) { this.message = message; }
+        }
+        try { throw ValidationError("bad input"); } catch (e) { print e.message; }
+        try { throw "plain"; } catch (e) { print e; }
+        fn early() {
+            try { return "from try"; } finally { print "finally runs"; }
+        }
+        print early();
+        var attempts = 0;
+        while (true) {
+            try {
+                attempts += 1;
+                if (attempts == 2) break;
+            } finally {
+                print attempts;
+            }
+        }
+        try {
+            try { throw 1; } catch (e) { throw e + 1; }
+        } catch (e) {
+            print e;
+        }
+        var x = "outer";
+        try {
+            var x = "inner";
+            { print missing; }
+        } catch (e) {
+            print e.kind;
+        }
+        print x;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result,
+            "DivisionByZero\nDivision by zero\n2\ncleanup\nbad input\nplain\nfinally runs\nfrom try\n1\n2\n2\nUndefinedVariable\nouter\n"
+        );
+    }
+
+    #[test]
+    fn test_uncaught_throw() {
+        let source = r#"
+        class AppError {
+            fn init(message) { this.message = message; }
+        }
+        try {
+            throw AppError("first");
+        } finally {
+            print "finally";
+        }
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let mut interpreter = Interpreter::new_with_output(output.clone());
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let error = interpreter.interprete(&mut stmts).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Uncaught error: AppError instance: first"));
+        assert_eq!(str::from_utf8(&output.borrow()).unwrap(), "finally\n");
+        assert!(parse(tokenize("try { print 1; }").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_for_in_loops() {
+        let source = r#"
+        for (c in "abc") print c;
+        for (i in 0..3) print i;
+        for (i in 1..=2) print i;
+        for (x in [10, 20, 30]) {
+            if (x == 20) continue;
+            print x;
+        }
+        for (k in {"only": 1}) print k;
+
+        class Countdown {
+            fn init(from) { this.current = from; }
+            fn next() {
+                if (this.current == 0) return nil;
+                this.current -= 1;
+                return this.current + 1;
+            }
+        }
+        class Bag {
+            fn init() { this.items = ["x", "y"]; }
+            fn iter() { return BagIterator(this.items); }
+        }
+        class BagIterator {
+            fn init(items) { this.items = items; this.index = 0; }
+            fn next() {
+                if (this.index >= List.len(this.items)) return nil;
+                this.index += 1;
+                return this.items[this.index - 1];
+            }
+        }
+        for (n in Countdown(3)) {
+            if (n == 1) break;
+            print n;
+        }
+        for (item in Bag()) print item;
+
+        var closures = [];
+        for (i in 0..2) List.push(closures, fn () { return i; });
+        print closures[0]() + closures[1]();
+        print 0..5;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result,
+            "a\nb\nc\n0\n1\n2\n1\n2\n10\n30\nonly\n3\n2\nx\ny\n1\n0..5\n"
+        );
+    }
+
+    #[test]
+    fn test_for_in_rejects_non_iterables() {
+        for source in [
+            "for (x in 42) print x;",
+            "class A {} for (x in A()) print x;",
+        ] {
+            let tokens = tokenize(source).expect("Tokenization failed");
+            let mut stmts = parse(tokens).expect("Parsing failed");
+
+            let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+            let mut interpreter = Interpreter::new_with_output(output);
+            let mut resolver = Resolver::new(&mut interpreter);
+            resolver.resolve(&mut stmts).expect("Resolving failed");
+
+            assert!(interpreter.interprete(&mut stmts).is_err());
+        }
+    }
+
+    #[test]
+    fn test_for_loop_continue_and_closures() {
+        let source = r#"
+        for (var i = 0; i < 5; i = i + 1) {
+            if (i == 1 || i == 3) continue;
+            print i;
+        }
+
+        var closures = [];
+        for (var i = 0; i < 3; i++) {
+            List.push(closures, fn () { return i; });
+        }
+        for (f in closures) print f();
+
+        var count = 0;
+        for (;;) {
+            count++;
+            if (count == 4) break;
+        }
+        print count;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(result, "0\n2\n4\n0\n1\n2\n4\n");
+    }
+
+    #[test]
+    fn test_string_interpolation() {
+        let source = r#"
+        var name = "Ada";
+        var age = 36;
+        print "Hello ${name}, next year ${age + 1}";
+        print "nested ${"inner ${name + "!"}"} done";
+        print "map ${ {"a": 1}["a"] } and \${literal} and $5";
+        print "${[1, 2]} ${nil} ${true}";
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result,
+            "Hello Ada, next year 37\nnested inner Ada! done\nmap 1 and ${literal} and $5\n[1, 2] Nil true\n"
+        );
+    }
+
+    #[test]
+    fn test_string_interpolation_parse_error_line() {
+        let source = "var a = 1;\nprint \"first\nsecond ${a +}\";";
+        let errors = parse(tokenize(source).unwrap()).unwrap_err();
+        assert_eq!(errors[0].line, 3);
+    }
+
+    #[test]
+    fn test_const_declarations() {
+        let source = r#"
+        const MAX = 10;
+        print MAX;
+        {
+            var MAX = 1;
+            MAX = MAX + 1;
+            print MAX;
+        }
+        fn limit() {
+            const STEP = 2;
+            return MAX + STEP;
+        }
+        print limit();
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(result, "10\n2\n12\n");
+    }
+
+    #[test]
+    fn test_const_reassignment_is_rejected_by_resolver() {
+        for source in [
+            "const MAX = 10;\nMAX = 11;",
+            "const MAX = 10;\nMAX += 1;",
+            "{ const n = 1;\n n++; }",
+            "const MAX = 10;\nfn f() { MAX = 1; }",
+        ] {
+            let tokens = tokenize(source).expect("Tokenization failed");
+            let mut stmts = parse(tokens).expect("Parsing failed");
+
+            let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+            let mut interpreter = Interpreter::new_with_output(output);
+            let mut resolver = Resolver::new(&mut interpreter);
+            let error = resolver.resolve(&mut stmts).unwrap_err();
+
+            assert!(
+                error.to_string().contains("declared on line 1"),
+                "{}: {}",
+                source,
+                error
+            );
+        }
+        assert!(parse(tokenize("const MAX;").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_const_globals_are_protected_at_runtime() {
+        let tokens = tokenize("const MAX = 10; MAX = 11;").expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let mut interpreter = Interpreter::new_with_output(output);
+
+        let error = interpreter.interprete(&mut stmts).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Cannot assign to constant 'MAX'"));
+    }
+
+    #[test]
+    fn test_match_statement() {
+        let source = r#"
+        class Point { fn init(x, y) { this.x = x; this.y = y; } }
+        class Point3 < Point { fn init(x, y, z) { super.init(x, y); this.z = z; } }
+        fn describe(v) {
+            match (v) {
+                1 | 2 => print "small";
+                -1 => print "minus one";
+                "a" => print "letter a";
+                nil => print "nothing";
+                Point(x: 0, y) => print "on y axis at " + y;
+                Point(x, y) => print "point " + x + "," + y;
+                n if n > 10 => { print "big"; print n; },
+                _ => print "other";
+            }
+        }
+        describe(1); describe(2); describe(-1); describe("a"); describe(nil);
+        describe(Point(0, 5)); describe(Point3(1, 2, 3)); describe(42); describe(7);
+
+        var x = "outer";
+        match (3) { x => print x; }
+        print x;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result,
+            "small\nsmall\nminus one\nletter a\nnothing\non y axis at 5\npoint 1,2\nbig\n42\nother\n3\nouter\n"
+        );
+    }
+
+    #[test]
+    fn test_match_without_matching_arm() {
+        let tokens = tokenize("match (3) { 1 => print 1; }").expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let mut interpreter = Interpreter::new_with_output(output);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let error = interpreter.interprete(&mut stmts).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("No match arm matches the value '3'."));
+
+        assert!(parse(tokenize("match (1) { a | 2 => print a; }").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_match_unreachable_arm_warning() {
+        let source = "match (1) {\n  n if n > 0 => print n;\n  _ => print 0;\n  2 => print 2;\n}";
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let mut interpreter = Interpreter::new_with_output(output);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        assert_eq!(resolver.warnings.len(), 1);
+        assert!(resolver.warnings[0].starts_with("[line: 4]"));
+    }
+
+    #[test]
+    fn test_default_variadic_and_named_arguments() {
+        let source = r#"
+        fn f(a, b = a * 2, ...rest) { print a + " " + b + " " + rest; }
+        f(1); f(1, 5); f(1, 5, 6, 7); f(1, b: 9); f(a: 3);
+        fn g(x, y = 10) { return x + y; }
+        print g(y: 1, x: 2);
+        class P { fn init(x, y = 0) { this.x = x; this.y = y; } }
+        var p = P(1, y: 4);
+        print p.x + p.y;
+        print (|...xs| xs)(1, 2);
+        var h = fn (a = 1) { return a; };
+        print h();
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result,
+            "1 2 []\n1 5 []\n1 5 [6, 7]\n1 9 []\n3 6 []\n3\n5\n[1, 2]\n1\n"
+        );
+    }
+
+    #[test]
+    fn test_argument_errors_name_the_parameter() {
+        let cases = [
+            ("fn f(a, b) {} f(1);", "Missing argument for parameter 'b'."),
+            (
+                "fn f(a, b) {} f(1, a: 2);",
+                "Got multiple values for parameter 'a'.",
+            ),
+            (
+                "fn f(a) {} f(1, c: 2);",
+                "<fn f> has no parameter named 'c'.",
+            ),
+            (
+                "fn f(a, b = 1) {} f(1, 2, 3);",
+                "Expected 1 to 2 arguments but got 3.",
+            ),
+            (
+                "List.len(xs: 1);",
+                "<native fn len> doesn't take named arguments.",
+            ),
+        ];
+        for (source, message) in cases {
+            let tokens = tokenize(source).expect("Tokenization failed");
+            let mut stmts = parse(tokens).expect("Parsing failed");
+
+            let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+            let mut interpreter = Interpreter::new_with_output(output);
+            let mut resolver = Resolver::new(&mut interpreter);
+            resolver.resolve(&mut stmts).expect("Resolving failed");
+
+            let error = interpreter.interprete(&mut stmts).unwrap_err();
+            assert!(error.to_string().contains(message), "{}: {}", source, error);
+        }
+
+        for source in [
+            "fn f(...a, b) {}",
+            "fn f(a = 1, b) {}",
+            "fn f(...a = 1) {}",
+            "f(a: 1, 2);",
+        ] {
+            assert!(parse(tokenize(source).unwrap()).is_err(), "{}", source);
+        }
+    }
+
+    #[test]
+    fn test_getters_and_setters() {
+        let source = r#"
+        class Shape { get area() { return 0; } get name() { return "shape"; } }
+        class Circle < Shape {
+            fn init(r) { this._r = r; }
+            get radius() { return this._r; }
+            set radius(v) { if (v < 0) throw "negative"; this._r = v; }
+            get area() { return 3 * this._r * this._r; }
+            get description() { return super.name + " with base area " + super.area; }
+        }
+        var c = Circle(2);
+        print c.radius; print c.area; print c.name;
+        c.radius = 3; print c.area;
+        c.radius += 1; print c.radius;
+        c.radius++; print c.radius;
+        print c.description;
+        try { c.radius = -1; } catch (e) { print e; }
+        try { c.area = 1; } catch (e) { print e.message; }
+        class M { fn get(x) { return x; } }
+        print M().get(5);
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result,
+            "2\n12\nshape\n27\n4\n5\nshape with base area 0\nnegative\nProperty 'area' has a getter but no setter.\n5\n"
+        );
+
+        for source in [
+            "class A { get x(a) { return a; } }",
+            "class A { set x() {} }",
+            "class A { set x(a, b) {} }",
+            "class A { static get x() { return 1; } }",
+        ] {
+            assert!(parse(tokenize(source).unwrap()).is_err(), "{}", source);
+        }
+    }
+
+    #[test]
+    fn test_operator_overloading() {
+        let source = r#"
+        class Vector {
+          fn init(x, y) { this.x = x; this.y = y; }
+          fn __add__(o) { return Vector(this.x + o.x, this.y + o.y); }
+          fn __sub__(o) { return Vector(this.x - o.x, this.y - o.y); }
+          fn __mul__(k) { return Vector(this.x * k, this.y * k); }
+          fn __eq__(o) { return this.x == o.x && this.y == o.y; }
+          fn __lt__(o) { return this.x * this.x + this.y * this.y < o.x * o.x + o.y * o.y; }
+          fn __neg__() { return Vector(-this.x, -this.y); }
+          fn __str__() { return "Vector(${this.x}, ${this.y})"; }
+        }
+        var a = Vector(1, 2); var b = Vector(3, 4);
+        print a + b; print b - a; print a * 3; print -a;
+        print a == Vector(1, 2); print a != b; print a < b;
+        print [a, b]; print "a is " + a; print "${b}";
+        var c = a; c += b; print c;
+        class Plain {} var p = Plain(); print p == p; print p == Plain(); print p;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result,
+            "Vector(4, 6)\nVector(2, 2)\nVector(3, 6)\nVector(-1, -2)\ntrue\ntrue\ntrue\n\
+             [Vector(1, 2), Vector(3, 4)]\na is Vector(1, 2)\nVector(3, 4)\nVector(4, 6)\n\
+             true\nfalse\nPlain instance\n"
+        );
+    }
+
+    #[test]
+    fn test_identity_and_equality() {
+        let source = r#"
+        class Node { fn init(v) { this.value = v; } }
+        class Money {
+          fn init(cents) { this.cents = cents; }
+          fn equals(other) { return other.cents == this.cents; }
+        }
+        var head = Node(1); var other = Node(1); var alias = head;
+        print head == head; print head == other; print head is alias; print head is other;
+        print Money(5) == Money(5); print Money(5) != Money(6); print Money(5) is Money(5);
+        var xs = [1]; var ys = [1];
+        print xs == ys; print xs is ys; print xs is xs;
+        fn f() {} var g = f;
+        print f == g; print f is g; print Node == Node; print Node == Money;
+        print 1 is 1; print "a" is "a"; print nil is nil;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>().join(" "),
+            "true false true false true true false true false true true true true false true true true"
+        );
+    }
+
+    #[test]
+    fn test_static_fields() {
+        let source = r#"
+        class Counter {
+            static var count = 0;
+            static var label;
+            fn init() { Counter.count += 1; }
+        }
+        Counter(); Counter();
+        print Counter.count; print Counter.label;
+        Counter.count++; print Counter.count;
+        Counter.label = "counter"; print Counter.label;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>().join(" "),
+            "2 Nil 3 counter"
+        );
+    }
+
+    #[test]
+    fn test_static_members_are_inherited() {
+        let source = r#"
+        class Base {
+            static var count = 1;
+            static fn describe() { return "count " + this.count; }
+        }
+        class Derived < Base {}
+        print Derived.count;
+        Derived.count = 5;
+        print Base.count; print Derived.describe();
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>().join(" "),
+            "1 5 count 5"
+        );
+    }
+
+    #[test]
+    fn test_this_in_static_method_is_class() {
+        let source = r#"
+        class Point {
+            static var created = 0;
+            static fn origin() { this.created += 1; return this(); }
+        }
+        print Point.origin(); print Point.created; print Point.origin;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>().join(" "),
+            "Point instance 1 <fn origin>"
+        );
+    }
+
+    #[test]
+    fn test_traits() {
+        let source = r#"
+        trait Named {
+            fn name();
+            fn greet() { return "Hello, " + this.name() + "!"; }
+        }
+        trait Loud {
+            fn shout() { return this.greet() + "!!"; }
+        }
+        class Animal { fn kind() { return "animal"; } }
+        class Dog < Animal with Named, Loud {
+            fn name() { return "Rex"; }
+        }
+        class Cat with Named {
+            fn name() { return "Tom"; }
+            fn greet() { return "Meow"; }
+        }
+        var dog = Dog();
+        print dog.greet(); print dog.shout(); print dog.kind();
+        print Cat().greet(); print Named;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>().join(" "),
+            "Hello, Rex! Hello, Rex!!! animal Meow <trait Named>"
+        );
+    }
+
+    #[test]
+    fn test_trait_conflicts_and_requirements() {
+        let source = "trait A { fn f() {} } trait B { fn f() {} } class C with A, B {}";
+        let mut stmts = parse(tokenize(source).unwrap()).expect("Parsing failed");
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let mut interpreter = Interpreter::new_with_output(output);
+        let error = Resolver::new(&mut interpreter)
+            .resolve(&mut stmts)
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Method 'f' is provided by both trait 'A' and trait 'B'"));
+
+        // defining the method in the class resolves the conflict
+        let source = "trait A { fn f() {} } trait B { fn f() {} } class C with A, B { fn f() {} }";
+        let mut stmts = parse(tokenize(source).unwrap()).expect("Parsing failed");
+        assert!(Resolver::new(&mut interpreter).resolve(&mut stmts).is_ok());
+
+        let source = "trait A { fn f(); } class C with A {}";
+        let mut stmts = parse(tokenize(source).unwrap()).expect("Parsing failed");
+        Resolver::new(&mut interpreter)
+            .resolve(&mut stmts)
+            .expect("Resolving failed");
+        let error = interpreter.interprete(&mut stmts).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Class 'C' must define method 'f' required by trait 'A'."));
+
+        // traits reached through another variable are checked when the class is created
+        let source = "trait T { fn a() {} } trait U { fn a() {} } var X = U; class C with T, X {}";
+        let mut stmts = parse(tokenize(source).unwrap()).expect("Parsing failed");
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let mut interpreter = Interpreter::new_with_output(output);
+        Resolver::new(&mut interpreter)
+            .resolve(&mut stmts)
+            .expect("Resolving failed");
+        let error = interpreter.interprete(&mut stmts).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Method 'a' is provided by both trait 'T' and trait 'U'"));
+    }
+
+    #[test]
+    fn test_instanceof() {
+        let source = r#"
+        class Animal {}
+        class Dog < Animal {}
+        class Robot {}
+        var dog = Dog();
+        print dog instanceof Dog; print dog instanceof Animal;
+        print dog instanceof Robot; print 1 instanceof Animal;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>().join(" "),
+            "true true false false"
+        );
+    }
+
+    #[test]
+    fn test_class_reflection() {
+        let source = r#"
+        class Animal { fn speak() {} fn eat() {} }
+        class Dog < Animal { fn speak() {} fn fetch() {} static fn create() {} }
+        print Dog.name; print Dog.superclass; print Dog.superclass.name;
+        print Animal.superclass; print Dog.methods();
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>().join(" "),
+            "Dog Animal Animal Nil [eat, fetch, speak]"
+        );
+    }
+
+    #[test]
+    fn test_private_members() {
+        let source = r#"
+        class Account {
+            fn init(balance) { this.#balance = balance; this.#count = 0; }
+            fn #allowed(amount) { return amount <= this.#balance; }
+            fn withdraw(amount) {
+                if (this.#allowed(amount)) { this.#balance -= amount; this.#count++; }
+                return this.#balance;
+            }
+            fn count() { return this.#count; }
+        }
+        class Savings < Account {
+            fn init(balance) { super.init(balance); this.#balance = "own"; }
+            fn own() { return this.#balance; }
+        }
+        var account = Account(100);
+        print account.withdraw(30); print account.withdraw(500); print account.count();
+        var savings = Savings(50);
+        print savings.withdraw(10); print savings.own(); print Account.methods();
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>().join(" "),
+            "70 70 1 40 own [count, init, withdraw]"
+        );
+    }
+
+    #[test]
+    fn test_private_members_are_not_accessible_from_outside() {
+        for source in [
+            "class A { fn init() { this.#x = 1; } } print A().#x;",
+            "class A { fn init() { this.#x = 1; } } var a = A(); a.#x = 2;",
+            "class A { fn same(other) { return other.#x; } }",
+            "trait T { fn f() { return this.#x; } }",
+        ] {
+            let mut stmts = parse(tokenize(source).unwrap()).expect("Parsing failed");
+            let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+            let mut interpreter = Interpreter::new_with_output(output);
+            let error = Resolver::new(&mut interpreter)
+                .resolve(&mut stmts)
+                .unwrap_err();
+            assert!(
+                error.to_string().contains(
+                    "Private member '#x' can only be accessed through 'this' inside its class."
+                ),
+                "{}: {}",
+                source,
+                error
+            );
+        }
+
+        // subclasses don't see the private members of their superclass
+        let source = "class A { fn init() { this.#x = 1; } } class B < A { fn x() { return this.#x; } } B().x();";
+        let mut stmts = parse(tokenize(source).unwrap()).expect("Parsing failed");
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let mut interpreter = Interpreter::new_with_output(output);
+        Resolver::new(&mut interpreter)
+            .resolve(&mut stmts)
+            .expect("Resolving failed");
+        let error = interpreter.interprete(&mut stmts).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Class 'B' has no private member '#x'."));
+
+        // not even when the subclass has the same name
+        let source = "class A { fn init() { this.#x = 1; } } var Base = A; { class A < Base { fn x() { return this.#x; } } A().x(); }";
+        let mut stmts = parse(tokenize(source).unwrap()).expect("Parsing failed");
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let mut interpreter = Interpreter::new_with_output(output);
+        Resolver::new(&mut interpreter)
+            .resolve(&mut stmts)
+            .expect("Resolving failed");
+        let error = interpreter.interprete(&mut stmts).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Class 'A' has no private member '#x'."));
+    }
+
+    #[test]
+    fn test_nan_comparisons() {
+        let source = r#"
+        var n = Math.sqrt(-1);
+        print n < 1; print n <= 1; print n > 1; print n >= 1; print n <= n;
+        print 1 < n; print 1 >= n;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>().join(" "),
+            "false false false false false false false"
+        );
+    }
+
+    #[test]
+    fn test_static_methods_bind_receiver_class() {
+        let source = r#"
+        class Base {
+            static fn create() { return this(); }
+            static fn who() { return this.name; }
+            static fn greet() { return "hello from " + this.name; }
+        }
+        class Sub < Base {
+            static fn greet() { return super.greet() + "!"; }
+        }
+        print type(Sub.create()); print type(Base.create());
+        print Sub.who(); print Base.who();
+        print Sub.greet();
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>().join(" "),
+            "Sub Base Sub Base hello from Sub!"
+        );
+    }
+
+    #[test]
+    fn test_equals_method_is_not_called_for_non_instances() {
+        let source = r#"
+        class Vec {
+            fn init(x) { this.x = x; }
+            fn equals(o) { return this.x == o.x; }
+        }
+        var v = Vec(1);
+        print v == nil; print v != nil; print v == 1; print v == Vec(1); print v != Vec(2);
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>().join(" "),
+            "false true false true true"
+        );
+    }
+
+    #[test]
+    fn test_range_display_and_equality() {
+        let source = r#"
+        print 0..=5; print 0..5; print 0..=5 == 0..6; print 0..=5 == 0..5;
+        var n = 0;
+        for (i in 9223372036854775806..=9223372036854775807) n += 1;
+        print n;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>().join(" "),
+            "0..=5 0..5 true false 2"
+        );
+    }
+
+    #[test]
+    fn test_caught_native_errors_share_the_error_class() {
+        let source = r#"
+try {
+    Math.sqrt("x");
+} catch (e) {
+    print e.line;
+    print e instanceof Error;
+}
+try { 1 / 0; } catch (e) { print type(e); print e instanceof Error; }
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>().join(" "),
+            "3 true Error true"
+        );
+    }
+
+    #[test]
+    fn test_match_arms_separated_by_commas() {
+        let source = r#"
+var out = [];
+fn size(v) {
+    match (v) {
+        1 | 2 => print "small",
+        "a" => print "letter",
+        x if x > 10 => List.push(out, x),
+        _ => { print "other"; }
+    }
+}
+size(2); size("a"); size(42); size(7);
+print out;
+match (1) { _ => print "last" }
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>().join(" "),
+            "small letter other [42] last"
+        );
+    }
+
+    #[test]
+    fn test_self_containing_containers() {
+        let source = r#"
+var xs = [1];
+List.push(xs, xs);
+print xs;
+var m = {"a": 1};
+m["self"] = m;
+m["list"] = xs;
+print m["self"] == m;
+var ys = [1];
+List.push(ys, ys);
+print xs == ys;
+print "" + xs;
+print [xs, xs];
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>().join(" "),
+            "[1, [...]] true true [1, [...]] [[1, [...]], [1, [...]]]"
+        );
+    }
+
+    #[test]
+    fn test_str_method_in_natives_and_errors() {
+        let source = r#"
+class Point {
+    fn init(x, y) { this.x = x; this.y = y; }
+    fn __str__() { return "(${this.x}, ${this.y})"; }
+}
+print String.join(" ", [Point(1, 2), Point(3, 4)]);
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>().join(" "),
+            "(1, 2) (3, 4)"
+        );
+    }
+
+    #[test]
+    fn test_str_method_in_uncaught_errors() {
+        let class = "class P { fn __str__() { return \"P!\"; } }\n";
+        for (source, expected) in [
+            ("throw P();", "Uncaught error: P!"),
+            (
+                "match (P()) { 1 => print 1, }",
+                "No match arm matches the value 'P!'.",
+            ),
+        ] {
+            let mut stmts = parse(tokenize(&format!("{}{}", class, source)).unwrap()).unwrap();
+            let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+            let mut interpreter = Interpreter::new_with_output(output);
+            let mut resolver = Resolver::new(&mut interpreter);
+            resolver.resolve(&mut stmts).expect("Resolving failed");
+
+            let error = interpreter.interprete(&mut stmts).unwrap_err();
+            assert!(error.to_string().contains(expected), "{}", error);
+        }
+    }
+
+    #[test]
+    fn test_errors_in_iterator_methods_propagate() {
+        let class = "class It { fn next() { return this.missing; } }\n";
+        for (source, expected) in [
+            (
+                "for (x in It()) { print x; }",
+                "Undefined property 'missing'.",
+            ),
+            (
+                "class Bad { fn iter() { throw \"no iter\"; } }\nfor (x in Bad()) {}",
+                "no iter",
+            ),
+            (
+                "class Bad { get next() { throw \"no next\"; } }\nfor (x in Bad()) {}",
+                "no next",
+            ),
+        ] {
+            let mut stmts = parse(tokenize(&format!("{}{}", class, source)).unwrap()).unwrap();
+            let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+            let mut interpreter = Interpreter::new_with_output(output);
+            let mut resolver = Resolver::new(&mut interpreter);
+            resolver.resolve(&mut stmts).expect("Resolving failed");
+
+            let error = interpreter.interprete(&mut stmts).unwrap_err();
+            assert!(error.to_string().contains(expected), "{}", error);
+        }
+    }
 }
@@ -1,18 +1,22 @@
 use crate::callable::Callable;
-use crate::class::ClassObject;
+use crate::class::{ClassInstance, ClassObject};
 use crate::environment::Environment;
 use crate::function::Function;
-use crate::object::Object;
+use crate::module::Module;
+use crate::object::{float_to_integer, MapKey, Object};
 use crate::runtime_error::RuntimeError;
 use crate::stmt_types::StmtVisitor;
 use crate::stmt_types::*;
 use crate::token::TokenType;
-use crate::Token;
+use crate::traits::Trait;
 use crate::{expr_types::*, native};
+use crate::{parser::parse, resolver::Resolver, scanner::tokenize, Token};
 
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
 /// A struct responsible for interpreting a list of statements.
@@ -22,12 +26,17 @@ use std::rc::Rc;
 pub struct Interpreter {
     /// holds any additional environments by the user
     pub environment: Rc<RefCell<Environment>>,
-    /// holds the outermost global environment
-    pub globals: Rc<RefCell<Environment>>,
     /// describres in what scope variables are
     pub locals: HashMap<Expr, usize>,
     /// output destination for print statements
     pub output: Rc<RefCell<dyn Write>>,
+    /// canonical paths of the files currently being executed, the innermost last;
+    /// relative imports are resolved against the last one
+    pub loading: Vec<PathBuf>,
+    /// modules that finished loading, keyed by their canonical path
+    pub modules: HashMap<PathBuf, Object>,
+    /// the class of the errors caught by `catch`, also defined as the global `Error`
+    pub error_class: ClassObject,
 }
 
 impl ExprVisitor<Object> for Interpreter {
@@ -81,15 +90,26 @@ impl ExprVisitor<Object> for Interpreter {
 
         match expr.prefix.token_type() {
             TokenType::Bang => Ok(Object::Boolean(!is_truthy(&right))),
-            TokenType::Minus => {
-                if let Object::Number(value) = right {
-                    Ok(Object::Number(-value))
-                } else {
-                    Err(RuntimeError::type_error(
-                        expr.prefix.line(),
-                        "Operand must be a number".to_string(),
-                    ))
+            TokenType::Minus => match right {
+                Object::Integer(value) => checked_integer(value.checked_neg(), &expr.prefix),
+                Object::Number(value) => Ok(Object::Number(-value)),
+                Object::ClassInstance(ref instance) => {
+                    match self.call_special_method(instance, "__neg__", Vec::new())? {
+                        Some(result) => Ok(result),
+                        None => Err(RuntimeError::type_error(
+                            expr.prefix.line(),
+                            "Operand must be a number".to_string(),
+                        )),
+                    }
                 }
+                _ => Err(RuntimeError::type_error(
+                    expr.prefix.line(),
+                    "Operand must be a number".to_string(),
+                )),
+            },
+            TokenType::BitwiseNot => {
+                let value = to_integer(&right, &expr.prefix)?;
+                Ok(Object::Integer(!value))
             }
             _ => panic!("Not a valid unary expression"),
         }
@@ -102,128 +122,7 @@ impl ExprVisitor<Object> for Interpreter {
         let left = expr.left.accept(self)?;
         let right = expr.right.accept(self)?;
 
-        match expr.operator.token_type() {
-            TokenType::Minus => {
-                if let (Object::Number(left_val), Object::Number(right_val)) = (&left, &right) {
-                    Ok(Object::Number(left_val - right_val))
-                } else {
-                    Err(RuntimeError::type_error(
-                        expr.operator.line(),
-                        "Operands musst be numbers".to_string(),
-                    ))
-                }
-            }
-            TokenType::Plus => match (&left, &right) {
-                (Object::Number(left_val), Object::Number(right_val)) => {
-                    Ok(Object::Number(left_val + right_val))
-                }
-                // String + anything = concatenation
-                (Object::String(left_val), right_val) => {
-                    Ok(Object::String(format!("{}{}", left_val, right_val)))
-                }
-                // Anything + String = concatenation
-                (left_val, Object::String(right_val)) => {
-                    Ok(Object::String(format!("{}{}", left_val, right_val)))
-                }
-                _ => Err(RuntimeError::type_error(
-                    expr.operator.line(),
-                    format!(
-                        "Cannot add {} and {}",
-                        match left {
-                            Object::Nil => "nil",
-                            Object::Boolean(_) => "boolean",
-                            Object::Number(_) => "number",
-                            Object::String(_) => "string",
-                            Object::Callable(_) => "callable",
-                            Object::Class(_) => "class",
-                            Object::ClassInstance(_) => "class instance",
-                        },
-                        match right {
-                            Object::Nil => "nil",
-                            Object::Boolean(_) => "boolean",
-                            Object::Number(_) => "number",
-                            Object::String(_) => "string",
-                            Object::Callable(_) => "callable",
-                            Object::Class(_) => "class",
-                            Object::ClassInstance(_) => "class instance",
-                        }
-                    ),
-                )),
-            },
-            TokenType::Slash => {
-                if let (Object::Number(left_val), Object::Number(right_val)) = (&left, &right) {
-                    if *right_val == 0.0 {
-                        return Err(RuntimeError::division_by_zero(
-                            expr.operator.line(),
-                            "Division by zero",
-                        ));
-                    }
-                    Ok(Object::Number(left_val / right_val))
-                } else {
-                    Err(RuntimeError::type_error(
-                        expr.operator.line(),
-                        "Operand must be numbersr".to_string(),
-                    ))
-                }
-            }
-            TokenType::Asterisk => {
-                if let (Object::Number(left_val), Object::Number(right_val)) = (&left, &right) {
-                    Ok(Object::Number(left_val * right_val))
-                } else {
-                    Err(RuntimeError::type_error(
-                        expr.operator.line(),
-                        "Operands must be numbers".to_string(),
-                    ))
-                }
-            }
-
-            // Comparison operators
-            TokenType::Greater => {
-                if let (Object::Number(left_val), Object::Number(right_val)) = (&left, &right) {
-                    Ok(Object::Boolean(left_val > right_val))
-                } else {
-                    Err(RuntimeError::type_error(
-                        expr.operator.line(),
-                        "Operands must be numbers".to_string(),
-                    ))
-                }
-            }
-            TokenType::GreaterEqual => {
-                if let (Object::Number(left_val), Object::Number(right_val)) = (&left, &right) {
-                    Ok(Object::Boolean(left_val >= right_val))
-                } else {
-                    Err(RuntimeError::type_error(
-                        expr.operator.line(),
-                        "Operands must be numbers".to_string(),
-                    ))
-                }
-            }
-            TokenType::Less => {
-                if let (Object::Number(left_val), Object::Number(right_val)) = (&left, &right) {
-                    Ok(Object::Boolean(left_val < right_val))
-                } else {
-                    Err(RuntimeError::type_error(
-                        expr.operator.line(),
-                        "Operands must be numbers".to_string(),
-                    ))
-                }
-            }
-            TokenType::LessEqual => {
-                if let (Object::Number(left_val), Object::Number(right_val)) = (&left, &right) {
-                    Ok(Object::Boolean(left_val <= right_val))
-                } else {
-                    Err(RuntimeError::type_error(
-                        expr.operator.line(),
-                        "Operands must be numbers".to_string(),
-                    ))
-                }
-            }
-
-            TokenType::EqualEqual => Ok(Object::Boolean(left == right)),
-            TokenType::BangEqual => Ok(Object::Boolean(!(left == right))),
-
-            _ => panic!("Unknown operator"),
-        }
+        self.binary_operation(left, &expr.operator, right)
     }
 
     fn visit_variable_expr(&mut self, expr: &VariableExpr) -> Result<Object, RuntimeError> {
@@ -231,98 +130,41 @@ impl ExprVisitor<Object> for Interpreter {
     }
 
     fn visit_assign_expr(&mut self, expr: &mut AssignExpr) -> Result<Object, RuntimeError> {
-        let val = expr.value.accept(self)?;
-
-        if let Some(distance) = self.locals.get(&Expr::Assign(expr.clone())) {
-            self.environment
-                .borrow_mut()
-                .assign_at(distance, expr.name.clone(), val.clone())?;
-        } else {
-            self.globals.borrow_mut().assign(&expr.name, &val)?;
-        }
+        let val = match expr.operator.clone() {
+            Some(operator) => {
+                let current = self.look_up_variable(&expr.name, Expr::Assign(expr.clone()))?;
+                let value = expr.value.accept(self)?;
+                self.binary_operation(current, &operator, value)?
+            }
+            None => expr.value.accept(self)?,
+        };
 
+        self.assign_variable(&expr.name, Expr::Assign(expr.clone()), &val)?;
         Ok(val)
     }
 
     fn visit_logical_expr(&mut self, expr: &mut LogicalExpr) -> Result<Object, RuntimeError> {
         let left = expr.left.accept(self)?;
 
-        if *expr.operator.token_type() == TokenType::Or {
-            if is_truthy(&left) {
-                return Ok(left);
-            }
-        } else if !is_truthy(&left) {
-            return Ok(left);
+        match expr.operator.token_type() {
+            TokenType::Or if is_truthy(&left) => return Ok(left),
+            TokenType::And if !is_truthy(&left) => return Ok(left),
+            TokenType::QuestionQuestion if !matches!(left, Object::Nil) => return Ok(left),
+            _ => (),
         }
 
         expr.right.accept(self)
     }
 
     fn visit_call_expr(&mut self, expr: &mut CallExpr) -> Result<Object, RuntimeError> {
-        let callee = expr.callee.accept(self)?;
-
-        let mut arguments: Vec<Object> = Vec::new();
-        for arg in &mut expr.arguments {
-            arguments.push(arg.accept(self)?);
-        }
-
-        match callee {
-            Object::Callable(func) => {
-                let arity = func.borrow().arity();
-                if arguments.len() != arity {
-                    Err(RuntimeError::argument_error(
-                        expr.paren.line(),
-                        format!("Expected {} arguments but got {}.", arity, arguments.len()),
-                    ))
-                } else {
-                    func.borrow().call(self, arguments)
-                }
-            }
-            Object::Class(class) => {
-                if arguments.len() != class.arity() {
-                    Err(RuntimeError::argument_error(
-                        expr.paren.line(),
-                        format!(
-                            "Expected {} arguments but got {}.",
-                            class.arity(),
-                            arguments.len()
-                        ),
-                    ))
-                } else {
-                    class.call(self, arguments)
-                }
-            }
-            _ => Err(RuntimeError::type_error(
-                expr.paren.line(),
-                "Can only call functions and classes.".to_string(),
-            )),
-        }
+        Ok(self.evaluate_call(expr)?.unwrap_or(Object::Nil))
     }
 
     fn visit_property_access_expr(
         &mut self,
         expr: &mut PropertyAccessExpr,
     ) -> Result<Object, RuntimeError> {
-        let object = expr.object.accept(self)?;
-
-        match object {
-            Object::ClassInstance(instance) => instance.get(expr.name.clone()),
-            Object::Class(class) => {
-                // Try to find static method
-                if let Some(method) = class.find_static_method(expr.name.lexeme()) {
-                    Ok(Object::Callable(method))
-                } else {
-                    Err(RuntimeError::undefined_variable(
-                        expr.name.line(),
-                        format!("Undefined static property '{}'.", expr.name.lexeme()),
-                    ))
-                }
-            }
-            _ => Err(RuntimeError::type_error(
-                expr.name.line(),
-                "Only instances and classes have properties.",
-            )),
-        }
+        Ok(self.evaluate_property_access(expr)?.unwrap_or(Object::Nil))
     }
 
     fn visit_property_assignment_expr(
@@ -331,15 +173,35 @@ impl ExprVisitor<Object> for Interpreter {
     ) -> Result<Object, RuntimeError> {
         let object = expr.object.accept(self)?;
 
-        if let Object::ClassInstance(mut instance) = object {
-            let value = expr.value.accept(self)?;
-            instance.set(expr.name.clone(), value.clone());
-            Ok(value)
-        } else {
-            Err(RuntimeError::type_error(
+        match object {
+            Object::ClassInstance(mut instance) => {
+                let value = match expr.operator.clone() {
+                    Some(operator) => {
+                        let current = instance.get(expr.name.clone(), self)?;
+                        let value = expr.value.accept(self)?;
+                        self.binary_operation(current, &operator, value)?
+                    }
+                    None => expr.value.accept(self)?,
+                };
+                instance.set(expr.name.clone(), value.clone(), self)?;
+                Ok(value)
+            }
+            Object::Class(class) => {
+                let value = match expr.operator.clone() {
+                    Some(operator) => {
+                        let current = class.get_static(&expr.name)?;
+                        let value = expr.value.accept(self)?;
+                        self.binary_operation(current, &operator, value)?
+                    }
+                    None => expr.value.accept(self)?,
+                };
+                class.set_static(&expr.name, value.clone());
+                Ok(value)
+            }
+            _ => Err(RuntimeError::type_error(
                 expr.name.line(),
-                "Only instances have fields.".to_string(),
-            ))
+                "Only instances and classes have fields.".to_string(),
+            )),
         }
     }
 
@@ -360,16 +222,8 @@ impl ExprVisitor<Object> for Interpreter {
             .borrow_mut()
             .get_at(&(distance - 1), "this".to_string())?;
 
-        let method = match superclass {
-            Object::Class(supclss) => match supclss.find_method(expr.method.lexeme()) {
-                Some(sc) => sc,
-                None => {
-                    return Err(RuntimeError::undefined_variable(
-                        expr.keyword.line(),
-                        format!("Undefinded property '{}'.", expr.method.lexeme()),
-                    ))
-                }
-            },
+        let supclss = match superclass {
+            Object::Class(supclss) => supclss,
             _ => {
                 return Err(RuntimeError::undefined_variable(
                     expr.keyword.line(),
@@ -377,18 +231,165 @@ impl ExprVisitor<Object> for Interpreter {
                 ))
             }
         };
+        let undefined = || {
+            RuntimeError::undefined_variable(
+                expr.keyword.line(),
+                format!("Undefinded property '{}'.", expr.method.lexeme()),
+            )
+        };
 
         match obj {
-            Object::ClassInstance(ci) => Ok(Object::Callable(Rc::new(RefCell::new(Box::new(
-                method.bind(ci)?,
-            )
-                as Box<dyn Callable>)))),
+            // in a static method 'this' is the class the method was called through
+            Object::Class(class) => supclss
+                .find_bound_static_method(expr.method.lexeme(), &class)?
+                .ok_or_else(undefined),
+            Object::ClassInstance(ci) => {
+                let method = supclss
+                    .find_method(expr.method.lexeme())
+                    .ok_or_else(undefined)?;
+                // a getter of the superclass is called right away
+                if method.declaration.is_getter {
+                    method.bind(ci)?.call(self, Vec::new())
+                } else {
+                    Ok(Object::Callable(Rc::new(RefCell::new(
+                        Box::new(method.bind(ci)?) as Box<dyn Callable>,
+                    ))))
+                }
+            }
             _ => Err(RuntimeError::undefined_variable(
                 expr.keyword.line(),
                 "'this' is not a class instance",
             )),
         }
     }
+
+    fn visit_list_expr(&mut self, expr: &mut ListExpr) -> Result<Object, RuntimeError> {
+        let mut elements = Vec::with_capacity(expr.elements.len());
+        for element in &mut expr.elements {
+            elements.push(element.accept(self)?);
+        }
+        Ok(Object::new_list(elements))
+    }
+
+    fn visit_index_expr(&mut self, expr: &mut IndexExpr) -> Result<Object, RuntimeError> {
+        Ok(self.evaluate_index(expr)?.unwrap_or(Object::Nil))
+    }
+
+    fn visit_index_assignment_expr(
+        &mut self,
+        expr: &mut IndexAssignmentExpr,
+    ) -> Result<Object, RuntimeError> {
+        let object = expr.object.accept(self)?;
+        let index = expr.index.accept(self)?;
+
+        let value = match expr.operator.clone() {
+            Some(operator) => {
+                let current = get_index(&object, &index, &expr.bracket)?;
+                let value = expr.value.accept(self)?;
+                self.binary_operation(current, &operator, value)?
+            }
+            None => expr.value.accept(self)?,
+        };
+
+        set_index(&object, &index, value, &expr.bracket)
+    }
+
+    fn visit_map_expr(&mut self, expr: &mut MapExpr) -> Result<Object, RuntimeError> {
+        let mut entries = HashMap::with_capacity(expr.entries.len());
+        for (key, value) in &mut expr.entries {
+            let key = to_map_key(&key.accept(self)?, &expr.brace)?;
+            let value = value.accept(self)?;
+            entries.insert(key, value);
+        }
+        Ok(Object::new_map(entries))
+    }
+
+    fn visit_update_expr(&mut self, expr: &mut UpdateExpr) -> Result<Object, RuntimeError> {
+        let (token_type, lexeme) = match expr.operator.token_type() {
+            TokenType::PlusPlus => (TokenType::Plus, "+"),
+            _ => (TokenType::Minus, "-"),
+        };
+        let operator = Token::new(
+            token_type,
+            lexeme,
+            None,
+            expr.operator.line(),
+            expr.operator.token_number(),
+        );
+
+        // evaluate the object (and index) of the target only once
+        let (old, new) = match &mut *expr.target {
+            Expr::Variable(variable) => {
+                let old = self.visit_variable_expr(variable)?;
+                let new = self.increment(&old, &operator, &expr.operator)?;
+                self.assign_variable(&variable.name, Expr::Variable(variable.clone()), &new)?;
+                (old, new)
+            }
+            Expr::PropertyAccess(property) => match property.object.accept(self)? {
+                Object::ClassInstance(mut instance) => {
+                    let old = instance.get(property.name.clone(), self)?;
+                    let new = self.increment(&old, &operator, &expr.operator)?;
+                    instance.set(property.name.clone(), new.clone(), self)?;
+                    (old, new)
+                }
+                Object::Class(class) => {
+                    let old = class.get_static(&property.name)?;
+                    let new = self.increment(&old, &operator, &expr.operator)?;
+                    class.set_static(&property.name, new.clone());
+                    (old, new)
+                }
+                _ => {
+                    return Err(RuntimeError::type_error(
+                        property.name.line(),
+                        "Only instances and classes have fields.".to_string(),
+                    ))
+                }
+            },
+            Expr::Index(index_expr) => {
+                let object = index_expr.object.accept(self)?;
+                let index = index_expr.index.accept(self)?;
+                let old = get_index(&object, &index, &index_expr.bracket)?;
+                let new = self.increment(&old, &operator, &expr.operator)?;
+                set_index(&object, &index, new.clone(), &index_expr.bracket)?;
+                (old, new)
+            }
+            _ => {
+                return Err(RuntimeError::other(
+                    expr.operator.line(),
+                    format!("Invalid '{}' target.", expr.operator.lexeme()),
+                ))
+            }
+        };
+
+        Ok(if expr.prefix { new } else { old })
+    }
+
+    fn visit_ternary_expr(&mut self, expr: &mut TernaryExpr) -> Result<Object, RuntimeError> {
+        if is_truthy(&expr.condition.accept(self)?) {
+            expr.then_branch.accept(self)
+        } else {
+            expr.else_branch.accept(self)
+        }
+    }
+
+    fn visit_interpolation_expr(
+        &mut self,
+        expr: &mut InterpolationExpr,
+    ) -> Result<Object, RuntimeError> {
+        let mut string = String::new();
+        for part in &mut expr.parts {
+            let value = part.accept(self)?;
+            string.push_str(&self.stringify(&value)?);
+        }
+        Ok(Object::String(string))
+    }
+
+    fn visit_lambda_expr(&mut self, expr: &mut LambdaExpr) -> Result<Object, RuntimeError> {
+        let function = Function::new(expr.declaration.clone(), self.environment.clone(), false);
+        Ok(Object::Callable(Rc::new(RefCell::new(
+            Box::new(function) as Box<dyn Callable>
+        ))))
+    }
 }
 
 impl StmtVisitor<()> for Interpreter {
@@ -399,6 +400,7 @@ impl StmtVisitor<()> for Interpreter {
 
     fn visit_print_stmt(&mut self, stmt: &mut Expr) -> Result<(), RuntimeError> {
         let value = stmt.accept(self)?;
+        let value = self.stringify(&value)?;
         writeln!(self.output.borrow_mut(), "{}", value)
             .map_err(|e| RuntimeError::other(0, format!("Print failed: {}", e)))?;
         self.output.borrow_mut().flush().ok();
@@ -411,27 +413,18 @@ impl StmtVisitor<()> for Interpreter {
             None => Object::Nil,
         };
 
-        self.environment
-            .borrow_mut()
-            .define(stmt.name.lexeme().to_string(), val)
+        let mut environment = self.environment.borrow_mut();
+        if stmt.is_const {
+            environment.define_const(stmt.name.lexeme().to_string(), val)
+        } else {
+            environment.define(stmt.name.lexeme().to_string(), val)
+        }
     }
 
     fn visit_block_stmt(&mut self, stmt: &mut Vec<Stmt>) -> Result<(), RuntimeError> {
-        let previous = Rc::clone(&self.environment);
-
         // Create a new environment that encloses the current one
-        self.environment = Rc::new(RefCell::new(Environment::new_enclosed(Rc::clone(
-            &previous,
-        ))));
-
-        for s in stmt {
-            s.evaluate(self)?;
-        }
-
-        // Restore previous environment
-        self.environment = previous;
-
-        Ok(())
+        let environment = Environment::new_enclosed(Rc::clone(&self.environment));
+        self.execute_block(stmt, environment)
     }
 
     ///If you compare this code to how the interpreter handles other syntax we’ve implemented, the part that makes control flow special is that Java if statement. Most other syntax trees always evaluate their subtrees. Here, we may not evaluate the then or else statement. If either of those has a side effect, the choice not to evaluate it becomes user visible.
@@ -457,6 +450,89 @@ impl StmtVisitor<()> for Interpreter {
         Ok(())
     }
 
+    fn visit_for_stmt(&mut self, stmt: &mut ForStmt) -> Result<(), RuntimeError> {
+        let previous = Rc::clone(&self.environment);
+        self.environment = Rc::new(RefCell::new(Environment::new_enclosed(Rc::clone(
+            &previous,
+        ))));
+
+        let result = self.run_for_loop(stmt, &previous);
+
+        // Restore previous environment
+        self.environment = previous;
+        result
+    }
+
+    fn visit_for_in_stmt(&mut self, stmt: &mut ForInStmt) -> Result<(), RuntimeError> {
+        let iterable = stmt.iterable.accept(self)?;
+
+        let values: Vec<Object> = match iterable {
+            Object::Range(start, end, inclusive) => {
+                // ranges are iterated lazily, they can be large
+                let range: Box<dyn Iterator<Item = i64>> = if inclusive {
+                    Box::new(start..=end)
+                } else {
+                    Box::new(start..end)
+                };
+                for i in range {
+                    if !self.for_in_iteration(stmt, Object::Integer(i))? {
+                        break;
+                    }
+                }
+                return Ok(());
+            }
+            Object::ClassInstance(instance) => {
+                let iterator = match self.call_method(&instance, "iter", &stmt.keyword)? {
+                    Some(Object::ClassInstance(iterator)) => iterator,
+                    Some(_) => {
+                        return Err(RuntimeError::type_error(
+                            stmt.keyword.line(),
+                            "iter() must return an object with a next() method.",
+                        ))
+                    }
+                    None => instance,
+                };
+                // next() returns nil once the iterator is exhausted, so nil can't be yielded
+                loop {
+                    let value = match self.call_method(&iterator, "next", &stmt.keyword)? {
+                        Some(Object::Nil) => break,
+                        Some(value) => value,
+                        None => {
+                            return Err(RuntimeError::type_error(
+                                stmt.keyword.line(),
+                                "Only objects with a next() or iter() method can be iterated.",
+                            ))
+                        }
+                    };
+                    if !self.for_in_iteration(stmt, value)? {
+                        break;
+                    }
+                }
+                return Ok(());
+            }
+            Object::String(string) => string
+                .chars()
+                .map(|c| Object::String(c.to_string()))
+                .collect(),
+            // iterate over a snapshot, so the loop body may modify the collection
+            Object::List(list) => list.borrow().clone(),
+            Object::Map(map) => map.borrow().keys().map(MapKey::to_object).collect(),
+            _ => {
+                return Err(RuntimeError::type_error(
+                    stmt.keyword.line(),
+                    "Can only iterate over strings, lists, maps, ranges and iterators.",
+                ))
+            }
+        };
+
+        for value in values {
+            if !self.for_in_iteration(stmt, value)? {
+                break;
+            }
+        }
+        Ok(())
+    }
+
     /// Register a new function in the environment for executing it later
     fn visit_function_stmt(&mut self, stmt: &mut FunctionStmt) -> Result<(), RuntimeError> {
         // .clone increases the RC
@@ -506,6 +582,50 @@ impl StmtVisitor<()> for Interpreter {
             .borrow_mut()
             .define(stmt.name.lexeme().into(), Object::Nil)?;
 
+        let mut traits = Vec::new();
+        for t in &stmt.traits {
+            match Expr::Variable(t.clone()).accept(self)? {
+                Object::Trait(t) => traits.push(t),
+                _ => {
+                    return Err(RuntimeError::type_error(
+                        t.name.line(),
+                        format!("'{}' is not a trait.", t.name.lexeme()),
+                    ))
+                }
+            }
+        }
+
+        // the resolver only catches conflicts between traits it can see by name
+        let mut providers: HashMap<&str, &Trait> = HashMap::new();
+        for t in &traits {
+            for method in t.methods.keys() {
+                if stmt.methods.iter().any(|m| m.name.lexeme() == method) {
+                    continue;
+                }
+                if let Some(other) = providers.insert(method, t) {
+                    return Err(RuntimeError::type_error(
+                        stmt.name.line(),
+                        format!(
+                            "Method '{}' is provided by both trait '{}' and trait '{}', class '{}' has to define it.",
+                            method,
+                            other.name,
+                            t.name,
+                            stmt.name.lexeme()
+                        ),
+                    ));
+                }
+            }
+        }
+
+        let mut static_fields = HashMap::new();
+        for field in &mut stmt.static_fields {
+            let value = match &mut field.initializer {
+                Some(init) => init.accept(self)?,
+                None => Object::Nil,
+            };
+            static_fields.insert(field.name.lexeme().to_string(), value);
+        }
+
         if let Some(supclss) = &superclass {
             self.environment = Rc::new(RefCell::new(Environment::new_enclosed(
                 self.environment.clone(),
@@ -516,53 +636,137 @@ impl StmtVisitor<()> for Interpreter {
                 .define("super".to_string(), *supclss.clone())?;
         }
 
-        let mut methods: HashMap<String, Function> = HashMap::new();
+        // trait methods come first so the methods of the class itself take precedence
+        let mut methods: Vec<Function> = traits
+            .iter()
+            .flat_map(|t| t.methods.values().cloned())
+            .collect();
         for method in &mut stmt.methods {
-            methods.insert(
-                method.name.lexeme().into(),
-                Function {
-                    declaration: method.clone(),
-                    closure: self.environment.clone(),
-                    is_initializer: method.name.lexeme().eq("init"),
-                },
-            );
+            methods.push(Function {
+                declaration: method.clone(),
+                closure: self.environment.clone(),
+                is_initializer: !method.is_static && method.name.lexeme().eq("init"),
+            });
         }
 
-        let class = ClassObject::new(stmt.name.lexeme(), superclass, methods);
-
+        let class = ClassObject::new(stmt.name.lexeme(), superclass, methods, static_fields);
         if stmt.superclass.is_some() {
             let new_env = self.environment.borrow().enclosing.clone().unwrap();
             self.environment = new_env;
         }
 
-        // Add static methods to global environment
-        let mut env = self.environment.borrow_mut();
-        for method in &mut stmt.methods {
-            if method.is_static {
-                let function = Function {
-                    declaration: method.clone(),
-                    closure: self.environment.clone(),
-                    is_initializer: false,
-                };
-                let full_name = format!("{}.{}", stmt.name.lexeme(), method.name.lexeme());
-                env.define(
-                    full_name,
-                    Object::Callable(Rc::new(RefCell::new(
-                        Box::new(function) as Box<dyn Callable>
-                    ))),
-                )?;
+        for t in &traits {
+            if let Some(missing) = t
+                .required
+                .iter()
+                .find(|name| class.find_method(name).is_none())
+            {
+                return Err(RuntimeError::type_error(
+                    stmt.name.line(),
+                    format!(
+                        "Class '{}' must define method '{}' required by trait '{}'.",
+                        stmt.name.lexeme(),
+                        missing,
+                        t.name
+                    ),
+                ));
             }
         }
 
-        env.assign(&stmt.name, &Object::Class(class))?;
+        self.environment
+            .borrow_mut()
+            .assign(&stmt.name, &Object::Class(class))?;
         Ok(())
     }
-}
 
-impl Interpreter {
-    /// Interprets a list of statements.
-    ///
-    /// Each statement in the provided vector is evaluated in order.
+    fn visit_trait_stmt(&mut self, stmt: &mut TraitStmt) -> Result<(), RuntimeError> {
+        let methods = stmt
+            .methods
+            .iter()
+            .map(|method| {
+                let function = Function {
+                    declaration: method.clone(),
+                    closure: self.environment.clone(),
+                    is_initializer: false,
+                };
+                (method.name.lexeme().to_string(), function)
+            })
+            .collect();
+        let required = stmt
+            .required
+            .iter()
+            .map(|name| name.lexeme().to_string())
+            .collect();
+
+        let t = Trait::new(stmt.name.lexeme().to_string(), methods, required);
+        self.environment
+            .borrow_mut()
+            .define(stmt.name.lexeme().to_string(), Object::Trait(Rc::new(t)))
+    }
+
+    fn visit_import_stmt(&mut self, stmt: &mut ImportStmt) -> Result<(), RuntimeError> {
+        let module = self.import_module(stmt)?;
+        self.environment
+            .borrow_mut()
+            .define(stmt.name.lexeme().to_string(), module)
+    }
+
+    fn visit_try_stmt(&mut self, stmt: &mut TryStmt) -> Result<(), RuntimeError> {
+        let mut result = self.visit_block_stmt(&mut stmt.body);
+
+        if let (Err(error), Some(catch)) = (&result, &mut stmt.catch) {
+            if let Some(value) = error.clone().into_value(&self.error_class) {
+                let mut environment = Environment::new_enclosed(Rc::clone(&self.environment));
+                environment.define(catch.name.lexeme().to_string(), value)?;
+                result = self.execute_block(&mut catch.body, environment);
+            }
+        }
+
+        // an error, return, break or continue in the finally block replaces the earlier outcome
+        if let Some(finally) = &mut stmt.finally {
+            self.visit_block_stmt(finally)?;
+        }
+        result
+    }
+
+    fn visit_throw_stmt(&mut self, stmt: &mut ThrowStmt) -> Result<(), RuntimeError> {
+        let value = stmt.value.accept(self)?;
+        Err(RuntimeError::Throw {
+            line: stmt.keyword.line(),
+            value,
+        })
+    }
+
+    fn visit_match_stmt(&mut self, stmt: &mut MatchStmt) -> Result<(), RuntimeError> {
+        let value = stmt.value.accept(self)?;
+
+        for arm in &mut stmt.arms {
+            // every arm gets its own scope for the variables bound by its pattern
+            let environment = Environment::new_enclosed(Rc::clone(&self.environment));
+            let previous =
+                std::mem::replace(&mut self.environment, Rc::new(RefCell::new(environment)));
+            let matched = self.match_arm(arm, &value);
+            self.environment = previous;
+
+            if matched? {
+                return Ok(());
+            }
+        }
+
+        Err(RuntimeError::other(
+            stmt.keyword.line(),
+            format!(
+                "No match arm matches the value '{}'.",
+                self.stringify(&value)?
+            ),
+        ))
+    }
+}
+
+impl Interpreter {
+    /// Interprets a list of statements.
+    ///
+    /// Each statement in the provided vector is evaluated in order.
     /// If any statement evaluation results in a `RuntimeError`, the
     /// interpretation process is halted and the error is returned.
     ///
@@ -578,6 +782,17 @@ impl Interpreter {
     pub fn interprete(&mut self, stmts: &mut Vec<Stmt>) -> Result<(), RuntimeError> {
         for stmt in stmts {
             match stmt.evaluate(self) {
+                // show uncaught instances through their `__str__` method
+                Err(RuntimeError::Throw {
+                    line,
+                    value: Object::ClassInstance(instance),
+                }) if instance.class().find_method("__str__").is_some() => {
+                    let value = Object::ClassInstance(instance);
+                    return Err(RuntimeError::Throw {
+                        line,
+                        value: Object::String(self.stringify(&value)?),
+                    });
+                }
                 Err(e) => return Err(e),
                 Ok(_) => continue,
             }
@@ -588,27 +803,336 @@ impl Interpreter {
 
     /// Creates a new interpreter with the given output destination.
     pub fn new_with_output(output: Rc<RefCell<dyn Write>>) -> Self {
-        let globals = Rc::new(RefCell::new(Environment::new()));
-        native::add_native_functions(&globals);
-
-        Interpreter {
-            environment: globals.to_owned(),
-            globals,
-            locals: HashMap::new(),
-            output,
-        }
+        let interpreter = Self::new_with_output_without_natives(output);
+        native::add_native_functions(&interpreter.environment);
+        interpreter
     }
 
     /// Creates a new interpreter with the given output destination.
     #[allow(dead_code)] // i need this here because the wasm lib needs this but the package warn me
     pub fn new_with_output_without_natives(output: Rc<RefCell<dyn Write>>) -> Self {
         let globals = Rc::new(RefCell::new(Environment::new()));
+        let error_class = RuntimeError::error_class();
+        globals
+            .borrow_mut()
+            .define("Error".to_string(), Object::Class(error_class.clone()))
+            .expect("Failed to define Error class");
 
         Interpreter {
-            environment: globals.to_owned(),
-            globals,
+            environment: globals,
             locals: HashMap::new(),
             output,
+            loading: Vec::new(),
+            modules: HashMap::new(),
+            error_class,
+        }
+    }
+
+    /// Runs a C-style for loop in the current environment, which holds the loop variables.
+    ///
+    /// Before every increment the loop variables are copied into a fresh environment, so
+    /// closures created during an iteration keep seeing the values of that iteration.
+    fn run_for_loop(
+        &mut self,
+        stmt: &mut ForStmt,
+        enclosing: &Rc<RefCell<Environment>>,
+    ) -> Result<(), RuntimeError> {
+        if let Some(initializer) = &mut stmt.initializer {
+            initializer.evaluate(self)?;
+        }
+
+        loop {
+            if let Some(condition) = &mut stmt.condition {
+                if !is_truthy(&condition.accept(self)?) {
+                    break;
+                }
+            }
+
+            match stmt.body.evaluate(self) {
+                Ok(_) | Err(RuntimeError::Continue) => {}
+                Err(RuntimeError::Break) => break,
+                Err(e) => return Err(e),
+            }
+
+            let mut next = Environment::new_enclosed(Rc::clone(enclosing));
+            next.values = self.environment.borrow().values.clone();
+            next.constants = self.environment.borrow().constants.clone();
+            self.environment = Rc::new(RefCell::new(next));
+
+            if let Some(increment) = &mut stmt.increment {
+                increment.accept(self)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs the body of a for-in loop once, with the loop variable bound to `value` in a
+    /// fresh environment so closures capture the value of their own iteration.
+    ///
+    /// Returns `false` if the body executed a `break`.
+    fn for_in_iteration(
+        &mut self,
+        stmt: &mut ForInStmt,
+        value: Object,
+    ) -> Result<bool, RuntimeError> {
+        let mut environment = Environment::new_enclosed(Rc::clone(&self.environment));
+        environment.define(stmt.name.lexeme().to_string(), value)?;
+        match self.execute_block(std::slice::from_mut(&mut *stmt.body), environment) {
+            Ok(_) | Err(RuntimeError::Continue) => Ok(true),
+            Err(RuntimeError::Break) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Calls the method `name` of an instance without arguments, or returns `None` if the
+    /// instance has no such method. Errors raised by the method are returned.
+    fn call_method(
+        &mut self,
+        instance: &ClassInstance,
+        name: &str,
+        token: &Token,
+    ) -> Result<Option<Object>, RuntimeError> {
+        let name = Token::new(
+            TokenType::Var,
+            name,
+            None,
+            token.line(),
+            token.token_number(),
+        );
+        if instance.field(name.lexeme()).is_none()
+            && instance.class().find_method(name.lexeme()).is_none()
+        {
+            return Ok(None);
+        }
+        match instance.get(name, self)? {
+            Object::Callable(method) => {
+                let arity = method.borrow().arity();
+                if !arity.contains(&0) {
+                    return Err(RuntimeError::argument_error(
+                        token.line(),
+                        format!("Expected {} to take no arguments.", method.borrow()),
+                    ));
+                }
+                let result = method.borrow().call(self, Vec::new());
+                result.map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Calls the special method `name` of an instance, like `__add__` or `__str__`, or
+    /// returns `None` if its class doesn't define one.
+    fn call_special_method(
+        &mut self,
+        instance: &ClassInstance,
+        name: &str,
+        arguments: Vec<Object>,
+    ) -> Result<Option<Object>, RuntimeError> {
+        match instance.class().find_method(name) {
+            Some(method) => method
+                .bind(instance.clone())?
+                .call(self, arguments)
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Applies a binary operator to an instance through its special method, e.g. `a + b`
+    /// becomes `a.__add__(b)`. Equality is handled by `values_equal`.
+    ///
+    /// Returns `None` if the class doesn't overload the operator.
+    fn overloaded_operation(
+        &mut self,
+        instance: &ClassInstance,
+        operator: &Token,
+        right: &Object,
+    ) -> Result<Option<Object>, RuntimeError> {
+        let name = match operator.token_type() {
+            TokenType::Plus => "__add__",
+            TokenType::Minus => "__sub__",
+            TokenType::Asterisk => "__mul__",
+            TokenType::Slash => "__div__",
+            TokenType::Percent => "__mod__",
+            TokenType::TildeSlash => "__floordiv__",
+            TokenType::StarStar => "__pow__",
+            TokenType::Less => "__lt__",
+            TokenType::LessEqual => "__le__",
+            TokenType::Greater => "__gt__",
+            TokenType::GreaterEqual => "__ge__",
+            _ => return Ok(None),
+        };
+
+        self.call_special_method(instance, name, vec![right.clone()])
+    }
+
+    /// Compares two values like `==` does.
+    ///
+    /// An instance whose class defines `__eq__`, or else `equals`, is compared to another
+    /// instance with that method, other values fall back to `Object`'s equality, so
+    /// `node == nil` doesn't call the method.
+    fn values_equal(&mut self, left: &Object, right: &Object) -> Result<bool, RuntimeError> {
+        if let (Object::ClassInstance(instance), Object::ClassInstance(_)) = (left, right) {
+            for name in ["__eq__", "equals"] {
+                if let Some(result) =
+                    self.call_special_method(instance, name, vec![right.clone()])?
+                {
+                    return Ok(is_truthy(&result));
+                }
+            }
+        }
+        Ok(left == right)
+    }
+
+    /// Converts a value to the string that `print` shows, using the `__str__` method of
+    /// instances, also inside lists and maps.
+    pub fn stringify(&mut self, value: &Object) -> Result<String, RuntimeError> {
+        self.stringify_nested(value, &mut Vec::new())
+    }
+
+    /// Stringifies a value inside the lists and maps in `seen`, a list or map that is
+    /// already in `seen` contains itself and is shown as `[...]` or `{...}`.
+    fn stringify_nested(
+        &mut self,
+        value: &Object,
+        seen: &mut Vec<*const ()>,
+    ) -> Result<String, RuntimeError> {
+        if let Some(ptr) = value.container_ptr() {
+            if seen.contains(&ptr) {
+                return Ok(match value {
+                    Object::List(_) => "[...]".to_string(),
+                    _ => "{...}".to_string(),
+                });
+            }
+            seen.push(ptr);
+        }
+
+        let result = match value {
+            Object::ClassInstance(instance) => {
+                match self.call_special_method(instance, "__str__", Vec::new())? {
+                    Some(Object::String(string)) => Ok(string),
+                    Some(_) => Err(RuntimeError::type_error(
+                        0,
+                        format!("__str__ of {} must return a string.", instance),
+                    )),
+                    None => Ok(value.to_string()),
+                }
+            }
+            Object::List(list) => {
+                let elements = list.borrow().clone();
+                let mut parts = Vec::with_capacity(elements.len());
+                for element in &elements {
+                    parts.push(self.stringify_nested(element, seen)?);
+                }
+                Ok(format!("[{}]", parts.join(", ")))
+            }
+            Object::Map(map) => {
+                let entries = map.borrow().clone();
+                let mut parts = Vec::with_capacity(entries.len());
+                for (key, value) in &entries {
+                    let value = self.stringify_nested(value, seen)?;
+                    parts.push(format!("{}: {}", key.to_object(), value));
+                }
+                Ok(format!("{{{}}}", parts.join(", ")))
+            }
+            _ => Ok(value.to_string()),
+        };
+
+        if value.container_ptr().is_some() {
+            seen.pop();
+        }
+        result
+    }
+
+    /// Executes `stmts` in the given environment and restores the current environment
+    /// afterwards, even if a statement fails.
+    pub fn execute_block(
+        &mut self,
+        stmts: &mut [Stmt],
+        environment: Environment,
+    ) -> Result<(), RuntimeError> {
+        let previous = std::mem::replace(&mut self.environment, Rc::new(RefCell::new(environment)));
+
+        let result = stmts.iter_mut().try_for_each(|stmt| stmt.evaluate(self));
+
+        // Restore previous environment
+        self.environment = previous;
+        result
+    }
+
+    /// Executes the body of a match arm if `value` matches its pattern and guard.
+    ///
+    /// Returns whether the arm was chosen. The variables bound by the pattern are defined
+    /// in the current environment.
+    fn match_arm(&mut self, arm: &mut MatchArm, value: &Object) -> Result<bool, RuntimeError> {
+        if !self.match_pattern(&mut arm.pattern, value)? {
+            return Ok(false);
+        }
+        if let Some(guard) = &mut arm.guard {
+            if !is_truthy(&guard.accept(self)?) {
+                return Ok(false);
+            }
+        }
+
+        arm.body.evaluate(self)?;
+        Ok(true)
+    }
+
+    /// Checks whether `value` matches `pattern`, defining the variables bound by the
+    /// pattern in the current environment.
+    fn match_pattern(
+        &mut self,
+        pattern: &mut Pattern,
+        value: &Object,
+    ) -> Result<bool, RuntimeError> {
+        match pattern {
+            Pattern::Literal(literal) => {
+                let literal = literal.accept(self)?;
+                self.values_equal(value, &literal)
+            }
+            Pattern::Wildcard => Ok(true),
+            Pattern::Binding(name) => {
+                self.environment
+                    .borrow_mut()
+                    .define(name.lexeme().to_string(), value.clone())?;
+                Ok(true)
+            }
+            Pattern::Alternatives(patterns) => {
+                for pattern in patterns {
+                    if self.match_pattern(pattern, value)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            Pattern::Class(pattern) => {
+                let class = match self.visit_variable_expr(&pattern.class)? {
+                    Object::Class(class) => class,
+                    _ => {
+                        return Err(RuntimeError::type_error(
+                            pattern.class.name.line(),
+                            format!("'{}' is not a class.", pattern.class.name.lexeme()),
+                        ))
+                    }
+                };
+                let instance = match value {
+                    Object::ClassInstance(instance) if instance.class().inherits_from(&class) => {
+                        instance
+                    }
+                    _ => return Ok(false),
+                };
+
+                for (field, field_pattern) in &mut pattern.fields {
+                    match instance.field(field.lexeme()) {
+                        Some(field_value) => {
+                            if !self.match_pattern(field_pattern, &field_value)? {
+                                return Ok(false);
+                            }
+                        }
+                        None => return Ok(false),
+                    }
+                }
+                Ok(true)
+            }
         }
     }
 
@@ -616,14 +1140,434 @@ impl Interpreter {
         self.locals.insert(expr, depth);
     }
 
+    /// Evaluates the object or callee of a property access, call or index expression.
+    ///
+    /// Returns `None` if an optional access (`?.`) in the chain was applied to nil, in
+    /// which case the rest of the chain is skipped and the whole chain evaluates to nil.
+    fn evaluate_chain(&mut self, expr: &mut Expr) -> Result<Option<Object>, RuntimeError> {
+        match expr {
+            Expr::Call(call) => self.evaluate_call(call),
+            Expr::PropertyAccess(property) => self.evaluate_property_access(property),
+            Expr::Index(index) => self.evaluate_index(index),
+            _ => expr.accept(self).map(Some),
+        }
+    }
+
+    /// Evaluates a call, or returns `None` if its callee short-circuited on an optional access.
+    fn evaluate_call(&mut self, expr: &mut CallExpr) -> Result<Option<Object>, RuntimeError> {
+        let callee = match self.evaluate_chain(&mut expr.callee)? {
+            Some(callee) => callee,
+            None => return Ok(None),
+        };
+
+        let mut arguments: Vec<Object> = Vec::new();
+        for arg in &mut expr.arguments {
+            arguments.push(arg.accept(self)?);
+        }
+        let mut named_arguments: Vec<(Token, Object)> = Vec::new();
+        for (name, arg) in &mut expr.named_arguments {
+            named_arguments.push((name.clone(), arg.accept(self)?));
+        }
+
+        let line = expr.paren.line();
+        let result = match callee {
+            // natives don't know the line they are called from
+            Object::Callable(func) => func
+                .borrow()
+                .call_with_arguments(self, line, arguments, named_arguments)
+                .map_err(|error| error.or_line(line)),
+            Object::Class(class) => {
+                class.call_with_arguments(self, line, arguments, named_arguments)
+            }
+            _ => Err(RuntimeError::type_error(
+                line,
+                "Can only call functions and classes.".to_string(),
+            )),
+        };
+        result.map(Some)
+    }
+
+    /// Evaluates a property access, or returns `None` if it short-circuited because it
+    /// (or an optional access further down the chain) was applied to nil.
+    fn evaluate_property_access(
+        &mut self,
+        expr: &mut PropertyAccessExpr,
+    ) -> Result<Option<Object>, RuntimeError> {
+        let object = match self.evaluate_chain(&mut expr.object)? {
+            Some(Object::Nil) if expr.optional => return Ok(None),
+            Some(object) => object,
+            None => return Ok(None),
+        };
+
+        let result = match object {
+            Object::ClassInstance(instance) => instance.get(expr.name.clone(), self),
+            Object::Module(module) => module.get(&expr.name),
+            Object::Class(class) => class.get_static(&expr.name),
+            _ => Err(RuntimeError::type_error(
+                expr.name.line(),
+                "Only instances, classes and modules have properties.",
+            )),
+        };
+        result.map(Some)
+    }
+
+    /// Evaluates an index expression, or returns `None` if its object short-circuited
+    /// on an optional access.
+    fn evaluate_index(&mut self, expr: &mut IndexExpr) -> Result<Option<Object>, RuntimeError> {
+        let object = match self.evaluate_chain(&mut expr.object)? {
+            Some(object) => object,
+            None => return Ok(None),
+        };
+        let index = expr.index.accept(self)?;
+
+        get_index(&object, &index, &expr.bracket).map(Some)
+    }
+
+    /// Applies a binary operator to two already evaluated operands.
+    ///
+    /// This is shared by binary expressions, compound assignments (`+=`, ...)
+    /// and increments (`++`, `--`).
+    fn binary_operation(
+        &mut self,
+        left: Object,
+        operator: &Token,
+        right: Object,
+    ) -> Result<Object, RuntimeError> {
+        if let Object::ClassInstance(instance) = &left {
+            if let Some(result) = self.overloaded_operation(instance, operator, &right)? {
+                return Ok(result);
+            }
+        }
+
+        match operator.token_type() {
+            TokenType::Minus => match (&left, &right) {
+                (Object::Integer(left_val), Object::Integer(right_val)) => {
+                    checked_integer(left_val.checked_sub(*right_val), operator)
+                }
+                _ => {
+                    let (left_val, right_val) = float_operands(&left, &right, operator)?;
+                    Ok(Object::Number(left_val - right_val))
+                }
+            },
+            TokenType::Plus => match (&left, &right) {
+                (Object::Integer(left_val), Object::Integer(right_val)) => {
+                    checked_integer(left_val.checked_add(*right_val), operator)
+                }
+                (
+                    Object::Number(_) | Object::Integer(_),
+                    Object::Number(_) | Object::Integer(_),
+                ) => {
+                    let (left_val, right_val) = float_operands(&left, &right, operator)?;
+                    Ok(Object::Number(left_val + right_val))
+                }
+                // String + anything = concatenation
+                (Object::String(left_val), right_val) => Ok(Object::String(format!(
+                    "{}{}",
+                    left_val,
+                    self.stringify(right_val)?
+                ))),
+                // Anything + String = concatenation
+                (left_val, Object::String(right_val)) => Ok(Object::String(format!(
+                    "{}{}",
+                    self.stringify(left_val)?,
+                    right_val
+                ))),
+                _ => Err(RuntimeError::type_error(
+                    operator.line(),
+                    format!("Cannot add {} and {}", left.type_name(), right.type_name()),
+                )),
+            },
+            // `/` always produces a float, use `~/` for integer division
+            TokenType::Slash => {
+                let (left_val, right_val) = float_operands(&left, &right, operator)?;
+                if right_val == 0.0 {
+                    return Err(RuntimeError::division_by_zero(
+                        operator.line(),
+                        "Division by zero",
+                    ));
+                }
+                Ok(Object::Number(left_val / right_val))
+            }
+            TokenType::Asterisk => match (&left, &right) {
+                (Object::Integer(left_val), Object::Integer(right_val)) => {
+                    checked_integer(left_val.checked_mul(*right_val), operator)
+                }
+                _ => {
+                    let (left_val, right_val) = float_operands(&left, &right, operator)?;
+                    Ok(Object::Number(left_val * right_val))
+                }
+            },
+            TokenType::Percent => {
+                let (left_val, right_val) = float_operands(&left, &right, operator)?;
+                if right_val == 0.0 {
+                    return Err(RuntimeError::division_by_zero(
+                        operator.line(),
+                        "Division by zero",
+                    ));
+                }
+                // floored modulo: the result takes the sign of the divisor
+                if let (Object::Integer(left_val), Object::Integer(right_val)) = (&left, &right) {
+                    let mut remainder = left_val.wrapping_rem(*right_val);
+                    if remainder != 0 && (remainder < 0) != (*right_val < 0) {
+                        remainder += right_val;
+                    }
+                    return Ok(Object::Integer(remainder));
+                }
+                let mut remainder = left_val % right_val;
+                if remainder != 0.0 && (remainder < 0.0) != (right_val < 0.0) {
+                    remainder += right_val;
+                }
+                Ok(Object::Number(remainder))
+            }
+            TokenType::TildeSlash => {
+                let (left_val, right_val) = float_operands(&left, &right, operator)?;
+                if right_val == 0.0 {
+                    return Err(RuntimeError::division_by_zero(
+                        operator.line(),
+                        "Division by zero",
+                    ));
+                }
+                if let (Object::Integer(left_val), Object::Integer(right_val)) = (&left, &right) {
+                    let quotient = checked_integer(left_val.checked_div(*right_val), operator)?;
+                    if let Object::Integer(quotient) = quotient {
+                        // round towards negative infinity instead of zero
+                        if left_val % right_val != 0 && (*left_val < 0) != (*right_val < 0) {
+                            return Ok(Object::Integer(quotient - 1));
+                        }
+                    }
+                    return Ok(quotient);
+                }
+                Ok(Object::Number((left_val / right_val).floor()))
+            }
+            TokenType::StarStar => match (&left, &right) {
+                (Object::Integer(left_val), Object::Integer(right_val)) if *right_val >= 0 => {
+                    let result = u32::try_from(*right_val)
+                        .ok()
+                        .and_then(|exponent| left_val.checked_pow(exponent));
+                    checked_integer(result, operator)
+                }
+                _ => {
+                    let (left_val, right_val) = float_operands(&left, &right, operator)?;
+                    Ok(Object::Number(left_val.powf(right_val)))
+                }
+            },
+
+            // Bitwise operators
+            TokenType::BitwiseAnd => {
+                let (left_val, right_val) =
+                    (to_integer(&left, operator)?, to_integer(&right, operator)?);
+                Ok(Object::Integer(left_val & right_val))
+            }
+            TokenType::BitwiseOr => {
+                let (left_val, right_val) =
+                    (to_integer(&left, operator)?, to_integer(&right, operator)?);
+                Ok(Object::Integer(left_val | right_val))
+            }
+            TokenType::BitwiseXor => {
+                let (left_val, right_val) =
+                    (to_integer(&left, operator)?, to_integer(&right, operator)?);
+                Ok(Object::Integer(left_val ^ right_val))
+            }
+            TokenType::ShiftLeft => {
+                let (left_val, right_val) = (
+                    to_integer(&left, operator)?,
+                    to_shift_amount(&right, operator)?,
+                );
+                Ok(Object::Integer(left_val << right_val))
+            }
+            TokenType::ShiftRight => {
+                let (left_val, right_val) = (
+                    to_integer(&left, operator)?,
+                    to_shift_amount(&right, operator)?,
+                );
+                Ok(Object::Integer(left_val >> right_val))
+            }
+
+            // Range operators, `..=` includes the end
+            TokenType::DotDot | TokenType::DotDotEqual => Ok(Object::Range(
+                to_integer(&left, operator)?,
+                to_integer(&right, operator)?,
+                *operator.token_type() == TokenType::DotDotEqual,
+            )),
+
+            // Comparison operators
+            TokenType::Greater => Ok(Object::Boolean(
+                compare_numbers(&left, &right, operator)?.is_some_and(Ordering::is_gt),
+            )),
+            TokenType::GreaterEqual => Ok(Object::Boolean(
+                compare_numbers(&left, &right, operator)?.is_some_and(Ordering::is_ge),
+            )),
+            TokenType::Less => Ok(Object::Boolean(
+                compare_numbers(&left, &right, operator)?.is_some_and(Ordering::is_lt),
+            )),
+            TokenType::LessEqual => Ok(Object::Boolean(
+                compare_numbers(&left, &right, operator)?.is_some_and(Ordering::is_le),
+            )),
+
+            TokenType::EqualEqual => Ok(Object::Boolean(self.values_equal(&left, &right)?)),
+            TokenType::BangEqual => Ok(Object::Boolean(!self.values_equal(&left, &right)?)),
+            TokenType::Is => Ok(Object::Boolean(left.is_identical(&right))),
+            TokenType::Instanceof => match right {
+                Object::Class(class) => Ok(Object::Boolean(matches!(
+                    left,
+                    Object::ClassInstance(instance) if instance.class().inherits_from(&class)
+                ))),
+                _ => Err(RuntimeError::type_error(
+                    operator.line(),
+                    "Right operand of 'instanceof' must be a class.",
+                )),
+            },
+
+            _ => panic!("Unknown operator"),
+        }
+    }
+
+    /// Adds or subtracts one for `++`/`--`, which only apply to numbers.
+    fn increment(
+        &mut self,
+        value: &Object,
+        operator: &Token,
+        update: &Token,
+    ) -> Result<Object, RuntimeError> {
+        if value.as_f64().is_none() {
+            return Err(RuntimeError::type_error(
+                update.line(),
+                format!("Operand of '{}' must be a number", update.lexeme()),
+            ));
+        }
+        self.binary_operation(value.clone(), operator, Object::Integer(1))
+    }
+
+    /// Assigns to the variable `name`, using the scope distance the resolver recorded for `expr`.
+    fn assign_variable(
+        &mut self,
+        name: &Token,
+        expr: Expr,
+        value: &Object,
+    ) -> Result<(), RuntimeError> {
+        if let Some(distance) = self.locals.get(&expr) {
+            self.environment
+                .borrow_mut()
+                .assign_at(distance, name.clone(), value.clone())?;
+        } else {
+            self.current_globals().borrow_mut().assign(name, value)?;
+        }
+        Ok(())
+    }
+
     fn look_up_variable(&mut self, name: &Token, expr: Expr) -> Result<Object, RuntimeError> {
         if let Some(distance) = self.locals.get(&expr) {
             self.environment
                 .borrow()
                 .get_at(distance, name.lexeme().to_owned())
         } else {
-            self.globals.borrow().get(name)
+            self.current_globals().borrow().get(name)
+        }
+    }
+
+    /// Returns the global environment of the module the running code was defined in.
+    ///
+    /// Every environment chain ends in the globals of a module (or of the main program),
+    /// so functions imported from another module keep seeing their own globals.
+    fn current_globals(&self) -> Rc<RefCell<Environment>> {
+        let mut environment = Rc::clone(&self.environment);
+        loop {
+            let enclosing = environment.borrow().enclosing.clone();
+            match enclosing {
+                Some(enclosing) => environment = enclosing,
+                None => return environment,
+            }
+        }
+    }
+
+    /// Loads the module named by an import statement, or returns it from the cache
+    /// if it has been loaded before.
+    ///
+    /// The path is resolved relative to the directory of the importing file. The module
+    /// is executed in a fresh global environment, which then becomes the module object.
+    fn import_module(&mut self, stmt: &ImportStmt) -> Result<Object, RuntimeError> {
+        let line = stmt.keyword.line();
+        let relative = match stmt.path.literal() {
+            Some(Object::String(path)) => path.clone(),
+            _ => String::new(),
+        };
+        let base = match self.loading.last() {
+            Some(file) => file.parent().map(Path::to_path_buf).unwrap_or_default(),
+            None => PathBuf::new(),
+        };
+        let path = base.join(&relative).canonicalize().map_err(|e| {
+            RuntimeError::other(line, format!("Cannot import '{}': {}", relative, e))
+        })?;
+
+        if let Some(module) = self.modules.get(&path) {
+            return Ok(module.clone());
         }
+
+        if let Some(start) = self.loading.iter().position(|file| *file == path) {
+            let cycle = self.loading[start..]
+                .iter()
+                .chain(std::iter::once(&path))
+                .map(|file| file.display().to_string())
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            return Err(RuntimeError::other(
+                line,
+                format!("Import cycle detected: {}", cycle),
+            ));
+        }
+
+        let source = std::fs::read_to_string(&path).map_err(|e| {
+            RuntimeError::other(line, format!("Cannot import '{}': {}", relative, e))
+        })?;
+        let tokens = tokenize(&source).map_err(|e| {
+            RuntimeError::other(
+                line,
+                format!("Cannot import '{}':\n  {}: {}", relative, path.display(), e),
+            )
+        })?;
+        let mut stmts = parse(tokens).map_err(|errors| {
+            let messages = errors
+                .iter()
+                .map(|error| format!("\n  {}: {}", path.display(), error))
+                .collect::<String>();
+            RuntimeError::other(line, format!("Cannot import '{}':{}", relative, messages))
+        })?;
+
+        let globals = Rc::new(RefCell::new(Environment::new()));
+        globals
+            .borrow_mut()
+            .define("Error".to_string(), Object::Class(self.error_class.clone()))?;
+        native::add_native_functions(&globals);
+        let builtins = globals
+            .borrow()
+            .values
+            .keys()
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        let previous = std::mem::replace(&mut self.environment, Rc::clone(&globals));
+        self.loading.push(path.clone());
+        let result = Resolver::new(self)
+            .resolve(&mut stmts)
+            .and_then(|_| self.interprete(&mut stmts));
+        self.loading.pop();
+        self.environment = previous;
+        result?;
+
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let exports = globals
+            .borrow()
+            .values
+            .keys()
+            .filter(|name| !builtins.contains(*name))
+            .cloned()
+            .collect();
+        let module = Object::Module(Rc::new(Module::new(name, globals, exports)));
+        self.modules.insert(path, module.clone());
+        Ok(module)
     }
 
     /// Default constructor, writes to stdout
@@ -654,6 +1598,173 @@ fn is_truthy(obj: &Object) -> bool {
     }
 }
 
+/// Converts an index value into a position inside a collection of `len` elements.
+///
+/// The index has to be a whole, non-negative number smaller than `len`.
+fn to_index(index: &Object, len: usize, bracket: &Token) -> Result<usize, RuntimeError> {
+    match index {
+        Object::Integer(i) => {
+            if *i < 0 || *i as u64 >= len as u64 {
+                Err(RuntimeError::other(
+                    bracket.line(),
+                    format!("Index {} out of bounds for length {}.", i, len),
+                ))
+            } else {
+                Ok(*i as usize)
+            }
+        }
+        Object::Number(n) if n.fract() == 0.0 => {
+            if *n < 0.0 || *n >= len as f64 {
+                Err(RuntimeError::other(
+                    bracket.line(),
+                    format!("Index {} out of bounds for length {}.", n, len),
+                ))
+            } else {
+                Ok(*n as usize)
+            }
+        }
+        _ => Err(RuntimeError::type_error(
+            bracket.line(),
+            "Index must be a whole number.",
+        )),
+    }
+}
+
+/// Reads the element at `index` of a list, string or map.
+fn get_index(object: &Object, index: &Object, bracket: &Token) -> Result<Object, RuntimeError> {
+    match object {
+        Object::List(list) => {
+            let list = list.borrow();
+            let i = to_index(index, list.len(), bracket)?;
+            Ok(list[i].clone())
+        }
+        Object::String(string) => {
+            let i = to_index(index, string.chars().count(), bracket)?;
+            Ok(Object::String(string.chars().nth(i).unwrap().to_string()))
+        }
+        Object::Map(map) => {
+            let key = to_map_key(index, bracket)?;
+            match map.borrow().get(&key) {
+                Some(value) => Ok(value.clone()),
+                None => Err(RuntimeError::other(
+                    bracket.line(),
+                    format!("Undefined key '{}'.", index),
+                )),
+            }
+        }
+        _ => Err(RuntimeError::type_error(
+            bracket.line(),
+            "Only lists, maps and strings can be indexed.",
+        )),
+    }
+}
+
+/// Stores `value` at `index` of a list or map and returns it.
+fn set_index(
+    object: &Object,
+    index: &Object,
+    value: Object,
+    bracket: &Token,
+) -> Result<Object, RuntimeError> {
+    match object {
+        Object::List(list) => {
+            let mut list = list.borrow_mut();
+            let i = to_index(index, list.len(), bracket)?;
+            list[i] = value.clone();
+            Ok(value)
+        }
+        Object::Map(map) => {
+            let key = to_map_key(index, bracket)?;
+            map.borrow_mut().insert(key, value.clone());
+            Ok(value)
+        }
+        _ => Err(RuntimeError::type_error(
+            bracket.line(),
+            "Only list and map elements can be assigned by index.",
+        )),
+    }
+}
+
+/// Converts a value into a key of an `Object::Map`.
+fn to_map_key(key: &Object, token: &Token) -> Result<MapKey, RuntimeError> {
+    MapKey::from_object(key).ok_or_else(|| {
+        RuntimeError::type_error(
+            token.line(),
+            "Map keys must be strings, numbers, booleans or nil.",
+        )
+    })
+}
+
+/// Returns both operands of an arithmetic operator as floats.
+///
+/// Integers are promoted; any other operand is a type error.
+fn float_operands(
+    left: &Object,
+    right: &Object,
+    operator: &Token,
+) -> Result<(f64, f64), RuntimeError> {
+    match (left.as_f64(), right.as_f64()) {
+        (Some(left_val), Some(right_val)) => Ok((left_val, right_val)),
+        _ => Err(RuntimeError::type_error(
+            operator.line(),
+            "Operands must be numbers".to_string(),
+        )),
+    }
+}
+
+/// Wraps the result of a checked integer operation, reporting overflow as an error.
+fn checked_integer(result: Option<i64>, operator: &Token) -> Result<Object, RuntimeError> {
+    result.map(Object::Integer).ok_or_else(|| {
+        RuntimeError::other(
+            operator.line(),
+            format!("Integer overflow in '{}'", operator.lexeme()),
+        )
+    })
+}
+
+/// Orders two numbers for a comparison operator.
+///
+/// Two integers are compared exactly, everything else is compared as floats. Returns
+/// `None` if either operand is NaN, which makes every comparison false.
+fn compare_numbers(
+    left: &Object,
+    right: &Object,
+    operator: &Token,
+) -> Result<Option<Ordering>, RuntimeError> {
+    if let (Object::Integer(left_val), Object::Integer(right_val)) = (left, right) {
+        return Ok(Some(left_val.cmp(right_val)));
+    }
+    let (left_val, right_val) = float_operands(left, right, operator)?;
+    Ok(left_val.partial_cmp(&right_val))
+}
+
+/// Converts an operand of a bitwise operator into an integer.
+///
+/// Bitwise operators only make sense on whole numbers, so fractional or
+/// non-numeric operands are rejected with a type error.
+fn to_integer(value: &Object, operator: &Token) -> Result<i64, RuntimeError> {
+    match value {
+        Object::Integer(i) => Ok(*i),
+        Object::Number(n) if float_to_integer(*n).is_some() => Ok(*n as i64),
+        _ => Err(RuntimeError::type_error(
+            operator.line(),
+            format!("Operands of '{}' must be whole numbers", operator.lexeme()),
+        )),
+    }
+}
+
+/// Converts the right operand of `<<` or `>>` into a shift amount in `0..64`.
+fn to_shift_amount(value: &Object, operator: &Token) -> Result<u32, RuntimeError> {
+    let amount = to_integer(value, operator)?;
+    if !(0..64).contains(&amount) {
+        return Err(RuntimeError::other(
+            operator.line(),
+            format!("Shift amount {} must be between 0 and 63", amount),
+        ));
+    }
+    Ok(amount as u32)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::expr_types::*;
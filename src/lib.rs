@@ -1,9 +1,14 @@
+// `RuntimeError` doubles as the carrier for `return`/`break`/`continue`, so it is
+// intentionally large; boxing it would only add an allocation on every return.
+#![allow(clippy::result_large_err)]
+
 mod callable;
 mod class;
 mod environment;
 mod expr_types;
 mod function;
 mod interpreter;
+mod module;
 mod native;
 mod object;
 mod parser;
@@ -14,6 +19,7 @@ mod scanner;
 mod scanner_error;
 mod stmt_types;
 mod token;
+mod traits;
 
 use interpreter::Interpreter;
 use parser::parse;
@@ -1,3 +1,7 @@
+// `RuntimeError` doubles as the carrier for `return`/`break`/`continue`, so it is
+// intentionally large; boxing it would only add an allocation on every return.
+#![allow(clippy::result_large_err)]
+
 mod callable;
 mod class;
 mod environment;
@@ -5,6 +9,7 @@ mod expr_types;
 mod function;
 mod integration_tests;
 mod interpreter;
+mod module;
 mod native;
 mod object;
 mod parser;
@@ -15,6 +20,7 @@ mod scanner;
 mod scanner_error;
 mod stmt_types;
 mod token;
+mod traits;
 
 use interpreter::Interpreter;
 use parser::parse;
@@ -51,7 +57,6 @@ The language supported by this interpreter includes:
 *   Functions (`fn`)
 *   Classes (`class`)
 */
-
 fn main() -> ExitCode {
     let args: Vec<String> = env::args().collect();
 
@@ -75,13 +80,13 @@ fn main() -> ExitCode {
             }
         };
 
-        run(&source)
+        run(&source, file_path)
     } else {
         run_repl()
     }
 }
 
-fn run(source: &str) -> ExitCode {
+fn run(source: &str, file_path: &str) -> ExitCode {
     let tokens = match tokenize(source) {
         Ok(t) => t,
         Err(e) => {
@@ -101,6 +106,10 @@ fn run(source: &str) -> ExitCode {
     };
 
     let mut interpreter = Interpreter::new();
+    // imports are resolved relative to the file that contains them
+    if let Ok(path) = fs::canonicalize(file_path) {
+        interpreter.loading.push(path);
+    }
 
     let mut resolver = Resolver::new(&mut interpreter);
     match resolver.resolve(&mut stmts) {
@@ -110,6 +119,9 @@ fn run(source: &str) -> ExitCode {
             return ExitCode::from(70);
         }
     };
+    for warning in &resolver.warnings {
+        eprintln!("\x1b[33;49;1mWarning: {}\x1b[0m", warning);
+    }
 
     match interpreter.interprete(&mut stmts) {
         Ok(_) => ExitCode::SUCCESS,
@@ -0,0 +1,52 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::rc::Rc;
+
+use crate::{environment::Environment, object::Object, runtime_error::RuntimeError, token::Token};
+
+/// Represents a loaded module, created by an `import` statement.
+///
+/// Every module is executed in its own global environment. The names it defines
+/// at the top level are exported and can be read as properties of the module, the
+/// built-in globals of that environment are not.
+#[derive(Debug, Clone)]
+pub struct Module {
+    /// The file name of the module, without its extension.
+    pub name: String,
+    /// The global environment the module was executed in.
+    pub globals: Rc<RefCell<Environment>>,
+    /// The names defined by the module itself.
+    pub exports: HashSet<String>,
+}
+
+impl Module {
+    pub fn new(name: String, globals: Rc<RefCell<Environment>>, exports: HashSet<String>) -> Self {
+        Module {
+            name,
+            globals,
+            exports,
+        }
+    }
+
+    /// Looks up a name exported by the module.
+    pub fn get(&self, name: &Token) -> Result<Object, RuntimeError> {
+        let value = match self.exports.contains(name.lexeme()) {
+            true => self.globals.borrow().values.get(name.lexeme()).cloned(),
+            false => None,
+        };
+        match value {
+            Some(value) => Ok(value),
+            None => Err(RuntimeError::undefined_variable(
+                name.line(),
+                format!("Module '{}' has no member '{}'.", self.name, name.lexeme()),
+            )),
+        }
+    }
+}
+
+impl Display for Module {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<module {}>", self.name)
+    }
+}
@@ -6,6 +6,7 @@ use crate::runtime_error::RuntimeError;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::ops::RangeInclusive;
 use std::rc::Rc;
 
 #[derive(Debug, Clone)]
@@ -56,8 +57,8 @@ impl Callable for ReadFileFn {
             Err(e) => Err(RuntimeError::other(0, format!("IO error: {e}"))),
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for ReadFileFn {
@@ -102,8 +103,8 @@ impl Callable for WriteFileFn {
             Err(e) => Err(RuntimeError::other(0, format!("IO error: {e}"))),
         }
     }
-    fn arity(&self) -> usize {
-        2
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
     }
 }
 impl Display for WriteFileFn {
@@ -152,8 +153,8 @@ impl Callable for AppendFileFn {
             Err(e) => Err(RuntimeError::other(0, format!("IO error: {e}"))),
         }
     }
-    fn arity(&self) -> usize {
-        2
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
     }
 }
 impl Display for AppendFileFn {
@@ -186,8 +187,8 @@ impl Callable for ExistsFn {
         };
         Ok(Object::Boolean(Path::new(path).exists()))
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for ExistsFn {
@@ -220,8 +221,8 @@ impl Callable for IsFileFn {
         };
         Ok(Object::Boolean(Path::new(path).is_file()))
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for IsFileFn {
@@ -254,8 +255,8 @@ impl Callable for IsDirFn {
         };
         Ok(Object::Boolean(Path::new(path).is_dir()))
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for IsDirFn {
@@ -291,16 +292,16 @@ impl Callable for ListDirFn {
                 let mut names = Vec::new();
                 for entry in entries.flatten() {
                     if let Some(name) = entry.file_name().to_str() {
-                        names.push(name.to_string());
+                        names.push(Object::String(name.to_string()));
                     }
                 }
-                Ok(Object::String(names.join(",")))
+                Ok(Object::new_list(names))
             }
             Err(e) => Err(RuntimeError::other(0, format!("IO error: {e}"))),
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for ListDirFn {
@@ -336,8 +337,8 @@ impl Callable for RemoveFileFn {
             Err(e) => Err(RuntimeError::other(0, format!("IO error: {e}"))),
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for RemoveFileFn {
@@ -373,8 +374,8 @@ impl Callable for MkdirFn {
             Err(e) => Err(RuntimeError::other(0, format!("IO error: {e}"))),
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for MkdirFn {
@@ -413,8 +414,8 @@ impl Callable for InputFn {
             Err(e) => Err(RuntimeError::other(0, format!("IO error: {e}"))),
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 
@@ -473,6 +474,8 @@ pub fn create_class() -> ClassObject {
         name: "IO".to_string(),
         superclass: None,
         methods,
+        setters: HashMap::new(),
         static_methods,
+        static_fields: Rc::new(RefCell::new(HashMap::new())),
     }
 }
@@ -0,0 +1,121 @@
+use crate::callable::Callable;
+use crate::class::ClassObject;
+use crate::interpreter::Interpreter;
+use crate::object::Object;
+use crate::runtime_error::RuntimeError;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
+#[derive(Debug, Clone)]
+struct LenFn;
+#[derive(Debug, Clone)]
+struct PushFn;
+#[derive(Debug, Clone)]
+struct PopFn;
+
+impl Callable for LenFn {
+    fn call(
+        &self,
+        _interpreter: &mut Interpreter,
+        _arguments: Vec<Object>,
+    ) -> Result<Object, RuntimeError> {
+        match &_arguments[0] {
+            Object::List(list) => Ok(Object::Integer(list.borrow().len() as i64)),
+            _ => Err(RuntimeError::argument_error(
+                0,
+                "len(list): argument must be a list",
+            )),
+        }
+    }
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
+    }
+}
+impl Display for LenFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<native fn len>")
+    }
+}
+
+impl Callable for PushFn {
+    fn call(
+        &self,
+        _interpreter: &mut Interpreter,
+        _arguments: Vec<Object>,
+    ) -> Result<Object, RuntimeError> {
+        match &_arguments[0] {
+            Object::List(list) => {
+                list.borrow_mut().push(_arguments[1].clone());
+                Ok(Object::Nil)
+            }
+            _ => Err(RuntimeError::argument_error(
+                0,
+                "push(list, value): first argument must be a list",
+            )),
+        }
+    }
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
+    }
+}
+impl Display for PushFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<native fn push>")
+    }
+}
+
+impl Callable for PopFn {
+    fn call(
+        &self,
+        _interpreter: &mut Interpreter,
+        _arguments: Vec<Object>,
+    ) -> Result<Object, RuntimeError> {
+        match &_arguments[0] {
+            Object::List(list) => match list.borrow_mut().pop() {
+                Some(value) => Ok(value),
+                None => Err(RuntimeError::other(0, "pop(list): list is empty")),
+            },
+            _ => Err(RuntimeError::argument_error(
+                0,
+                "pop(list): argument must be a list",
+            )),
+        }
+    }
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
+    }
+}
+impl Display for PopFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<native fn pop>")
+    }
+}
+
+pub fn create_class() -> ClassObject {
+    let methods = HashMap::new();
+    let mut static_methods = HashMap::new();
+    static_methods.insert(
+        "len".to_string(),
+        Rc::new(RefCell::new(Box::new(LenFn) as Box<dyn Callable>)),
+    );
+    static_methods.insert(
+        "push".to_string(),
+        Rc::new(RefCell::new(Box::new(PushFn) as Box<dyn Callable>)),
+    );
+    static_methods.insert(
+        "pop".to_string(),
+        Rc::new(RefCell::new(Box::new(PopFn) as Box<dyn Callable>)),
+    );
+
+    ClassObject {
+        name: "List".to_string(),
+        superclass: None,
+        methods,
+        setters: HashMap::new(),
+        static_methods,
+        static_fields: Rc::new(RefCell::new(HashMap::new())),
+    }
+}
@@ -0,0 +1,178 @@
+use crate::callable::Callable;
+use crate::class::ClassObject;
+use crate::interpreter::Interpreter;
+use crate::object::{MapKey, Object};
+use crate::runtime_error::RuntimeError;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
+#[derive(Debug, Clone)]
+struct LenFn;
+#[derive(Debug, Clone)]
+struct HasFn;
+#[derive(Debug, Clone)]
+struct DeleteFn;
+#[derive(Debug, Clone)]
+struct KeysFn;
+#[derive(Debug, Clone)]
+struct ValuesFn;
+
+/// Extracts the map behind the first argument of a `Map` native function.
+fn map_argument<'a>(
+    arguments: &'a [Object],
+    signature: &str,
+) -> Result<&'a Rc<RefCell<HashMap<MapKey, Object>>>, RuntimeError> {
+    match &arguments[0] {
+        Object::Map(map) => Ok(map),
+        _ => Err(RuntimeError::argument_error(
+            0,
+            format!("{signature}: first argument must be a map"),
+        )),
+    }
+}
+
+/// Converts the key argument of a `Map` native function into a `MapKey`.
+fn key_argument(key: &Object, signature: &str) -> Result<MapKey, RuntimeError> {
+    MapKey::from_object(key).ok_or_else(|| {
+        RuntimeError::argument_error(
+            0,
+            format!("{signature}: key must be a string, number, boolean or nil"),
+        )
+    })
+}
+
+impl Callable for LenFn {
+    fn call(
+        &self,
+        _interpreter: &mut Interpreter,
+        _arguments: Vec<Object>,
+    ) -> Result<Object, RuntimeError> {
+        let map = map_argument(&_arguments, "len(map)")?;
+        Ok(Object::Integer(map.borrow().len() as i64))
+    }
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
+    }
+}
+impl Display for LenFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<native fn len>")
+    }
+}
+
+impl Callable for HasFn {
+    fn call(
+        &self,
+        _interpreter: &mut Interpreter,
+        _arguments: Vec<Object>,
+    ) -> Result<Object, RuntimeError> {
+        let map = map_argument(&_arguments, "has(map, key)")?;
+        let key = key_argument(&_arguments[1], "has(map, key)")?;
+        Ok(Object::Boolean(map.borrow().contains_key(&key)))
+    }
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
+    }
+}
+impl Display for HasFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<native fn has>")
+    }
+}
+
+impl Callable for DeleteFn {
+    fn call(
+        &self,
+        _interpreter: &mut Interpreter,
+        _arguments: Vec<Object>,
+    ) -> Result<Object, RuntimeError> {
+        let map = map_argument(&_arguments, "delete(map, key)")?;
+        let key = key_argument(&_arguments[1], "delete(map, key)")?;
+        Ok(map.borrow_mut().remove(&key).unwrap_or(Object::Nil))
+    }
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
+    }
+}
+impl Display for DeleteFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<native fn delete>")
+    }
+}
+
+impl Callable for KeysFn {
+    fn call(
+        &self,
+        _interpreter: &mut Interpreter,
+        _arguments: Vec<Object>,
+    ) -> Result<Object, RuntimeError> {
+        let map = map_argument(&_arguments, "keys(map)")?;
+        let keys = map.borrow().keys().map(MapKey::to_object).collect();
+        Ok(Object::new_list(keys))
+    }
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
+    }
+}
+impl Display for KeysFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<native fn keys>")
+    }
+}
+
+impl Callable for ValuesFn {
+    fn call(
+        &self,
+        _interpreter: &mut Interpreter,
+        _arguments: Vec<Object>,
+    ) -> Result<Object, RuntimeError> {
+        let map = map_argument(&_arguments, "values(map)")?;
+        let values = map.borrow().values().cloned().collect();
+        Ok(Object::new_list(values))
+    }
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
+    }
+}
+impl Display for ValuesFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<native fn values>")
+    }
+}
+
+pub fn create_class() -> ClassObject {
+    let methods = HashMap::new();
+    let mut static_methods = HashMap::new();
+    static_methods.insert(
+        "len".to_string(),
+        Rc::new(RefCell::new(Box::new(LenFn) as Box<dyn Callable>)),
+    );
+    static_methods.insert(
+        "has".to_string(),
+        Rc::new(RefCell::new(Box::new(HasFn) as Box<dyn Callable>)),
+    );
+    static_methods.insert(
+        "delete".to_string(),
+        Rc::new(RefCell::new(Box::new(DeleteFn) as Box<dyn Callable>)),
+    );
+    static_methods.insert(
+        "keys".to_string(),
+        Rc::new(RefCell::new(Box::new(KeysFn) as Box<dyn Callable>)),
+    );
+    static_methods.insert(
+        "values".to_string(),
+        Rc::new(RefCell::new(Box::new(ValuesFn) as Box<dyn Callable>)),
+    );
+
+    ClassObject {
+        name: "Map".to_string(),
+        superclass: None,
+        methods,
+        setters: HashMap::new(),
+        static_methods,
+        static_fields: Rc::new(RefCell::new(HashMap::new())),
+    }
+}
@@ -1,11 +1,12 @@
 use crate::callable::Callable;
 use crate::class::ClassObject;
 use crate::interpreter::Interpreter;
-use crate::object::Object;
+use crate::object::{float_to_integer, Object};
 use crate::runtime_error::RuntimeError;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::ops::RangeInclusive;
 use std::rc::Rc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -55,6 +56,10 @@ pub struct MaxFn;
 pub struct ClampFn;
 #[derive(Debug, Clone)]
 pub struct RandomFn;
+#[derive(Debug, Clone)]
+pub struct IntFn;
+#[derive(Debug, Clone)]
+pub struct FloatFn;
 
 impl Callable for AbsFn {
     fn call(
@@ -68,7 +73,13 @@ impl Callable for AbsFn {
                 "not enough arguments in function".to_string() + &self.to_string(),
             )
         })?;
-        if let Object::Number(num) = arg {
+        if let Object::Integer(int) = arg {
+            return int
+                .checked_abs()
+                .map(Object::Integer)
+                .ok_or_else(|| RuntimeError::other(0, "integer overflow in abs".to_string()));
+        }
+        if let Some(num) = arg.as_f64() {
             Ok(Object::Number(num.abs()))
         } else {
             Err(RuntimeError::other(
@@ -77,8 +88,8 @@ impl Callable for AbsFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for AbsFn {
@@ -100,7 +111,7 @@ impl Callable for SqrtFn {
             )
         })?;
 
-        if let Object::Number(num) = arg {
+        if let Some(num) = arg.as_f64() {
             Ok(Object::Number(num.sqrt()))
         } else {
             Err(RuntimeError::other(
@@ -109,8 +120,8 @@ impl Callable for SqrtFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for SqrtFn {
@@ -133,8 +144,8 @@ impl Callable for PowFn {
         }
         let base = &_arguments[_arguments.len() - 2];
         let exp = &_arguments[_arguments.len() - 1];
-        if let (Object::Number(b), Object::Number(e)) = (base, exp) {
-            Ok(Object::Number(b.powf(*e)))
+        if let (Some(b), Some(e)) = (base.as_f64(), exp.as_f64()) {
+            Ok(Object::Number(b.powf(e)))
         } else {
             Err(RuntimeError::other(
                 0,
@@ -142,8 +153,8 @@ impl Callable for PowFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        2
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
     }
 }
 impl Display for PowFn {
@@ -164,7 +175,7 @@ impl Callable for ExpFn {
                 "not enough arguments in function".to_string() + &self.to_string(),
             )
         })?;
-        if let Object::Number(num) = arg {
+        if let Some(num) = arg.as_f64() {
             Ok(Object::Number(num.exp()))
         } else {
             Err(RuntimeError::other(
@@ -173,8 +184,8 @@ impl Callable for ExpFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for ExpFn {
@@ -197,14 +208,14 @@ impl Callable for LogFn {
         }
         let value = &_arguments[_arguments.len() - 2];
         let base = &_arguments[_arguments.len() - 1];
-        if let (Object::Number(v), Object::Number(b)) = (value, base) {
-            if *v <= 0.0 || *b <= 0.0 {
+        if let (Some(v), Some(b)) = (value.as_f64(), base.as_f64()) {
+            if v <= 0.0 || b <= 0.0 {
                 return Err(RuntimeError::other(
                     0,
                     "logarithm arguments must be positive".to_string(),
                 ));
             }
-            Ok(Object::Number(v.log(*b)))
+            Ok(Object::Number(v.log(b)))
         } else {
             Err(RuntimeError::other(
                 0,
@@ -212,8 +223,8 @@ impl Callable for LogFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        2
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
     }
 }
 impl Display for LogFn {
@@ -234,8 +245,8 @@ impl Callable for Log10Fn {
                 "not enough arguments in function".to_string() + &self.to_string(),
             )
         })?;
-        if let Object::Number(num) = arg {
-            if *num <= 0.0 {
+        if let Some(num) = arg.as_f64() {
+            if num <= 0.0 {
                 return Err(RuntimeError::other(
                     0,
                     "log10 argument must be positive".to_string(),
@@ -249,8 +260,8 @@ impl Callable for Log10Fn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for Log10Fn {
@@ -272,7 +283,7 @@ impl Callable for SinFn {
             )
         })?;
 
-        if let Object::Number(num) = arg {
+        if let Some(num) = arg.as_f64() {
             // sin is rad
             Ok(Object::Number(num.sin()))
         } else {
@@ -282,8 +293,8 @@ impl Callable for SinFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for SinFn {
@@ -304,7 +315,7 @@ impl Callable for CosFn {
                 "not enough arguments in function".to_string() + &self.to_string(),
             )
         })?;
-        if let Object::Number(num) = arg {
+        if let Some(num) = arg.as_f64() {
             Ok(Object::Number(num.cos()))
         } else {
             Err(RuntimeError::other(
@@ -313,8 +324,8 @@ impl Callable for CosFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for CosFn {
@@ -335,7 +346,7 @@ impl Callable for TanFn {
                 "not enough arguments in function".to_string() + &self.to_string(),
             )
         })?;
-        if let Object::Number(num) = arg {
+        if let Some(num) = arg.as_f64() {
             Ok(Object::Number(num.tan()))
         } else {
             Err(RuntimeError::other(
@@ -344,8 +355,8 @@ impl Callable for TanFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for TanFn {
@@ -366,7 +377,7 @@ impl Callable for AsinFn {
                 "not enough arguments in function".to_string() + &self.to_string(),
             )
         })?;
-        if let Object::Number(num) = arg {
+        if let Some(num) = arg.as_f64() {
             Ok(Object::Number(num.asin()))
         } else {
             Err(RuntimeError::other(
@@ -375,8 +386,8 @@ impl Callable for AsinFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for AsinFn {
@@ -397,7 +408,7 @@ impl Callable for AcosFn {
                 "not enough arguments in function".to_string() + &self.to_string(),
             )
         })?;
-        if let Object::Number(num) = arg {
+        if let Some(num) = arg.as_f64() {
             Ok(Object::Number(num.acos()))
         } else {
             Err(RuntimeError::other(
@@ -406,8 +417,8 @@ impl Callable for AcosFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for AcosFn {
@@ -428,7 +439,7 @@ impl Callable for AtanFn {
                 "not enough arguments in function".to_string() + &self.to_string(),
             )
         })?;
-        if let Object::Number(num) = arg {
+        if let Some(num) = arg.as_f64() {
             Ok(Object::Number(num.atan()))
         } else {
             Err(RuntimeError::other(
@@ -437,8 +448,8 @@ impl Callable for AtanFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for AtanFn {
@@ -461,8 +472,8 @@ impl Callable for Atan2Fn {
         }
         let y = &_arguments[_arguments.len() - 2];
         let x = &_arguments[_arguments.len() - 1];
-        if let (Object::Number(y), Object::Number(x)) = (y, x) {
-            Ok(Object::Number(y.atan2(*x)))
+        if let (Some(y), Some(x)) = (y.as_f64(), x.as_f64()) {
+            Ok(Object::Number(y.atan2(x)))
         } else {
             Err(RuntimeError::other(
                 0,
@@ -470,8 +481,8 @@ impl Callable for Atan2Fn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        2
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
     }
 }
 impl Display for Atan2Fn {
@@ -492,7 +503,7 @@ impl Callable for FloorFn {
                 "not enough arguments in function".to_string() + &self.to_string(),
             )
         })?;
-        if let Object::Number(num) = arg {
+        if let Some(num) = arg.as_f64() {
             Ok(Object::Number(num.floor()))
         } else {
             Err(RuntimeError::other(
@@ -501,8 +512,8 @@ impl Callable for FloorFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for FloorFn {
@@ -523,7 +534,7 @@ impl Callable for CeilFn {
                 "not enough arguments in function".to_string() + &self.to_string(),
             )
         })?;
-        if let Object::Number(num) = arg {
+        if let Some(num) = arg.as_f64() {
             Ok(Object::Number(num.ceil()))
         } else {
             Err(RuntimeError::other(
@@ -532,8 +543,8 @@ impl Callable for CeilFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for CeilFn {
@@ -554,7 +565,7 @@ impl Callable for RoundFn {
                 "not enough arguments in function".to_string() + &self.to_string(),
             )
         })?;
-        if let Object::Number(num) = arg {
+        if let Some(num) = arg.as_f64() {
             Ok(Object::Number(num.round()))
         } else {
             Err(RuntimeError::other(
@@ -563,8 +574,8 @@ impl Callable for RoundFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for RoundFn {
@@ -585,7 +596,7 @@ impl Callable for TruncFn {
                 "not enough arguments in function".to_string() + &self.to_string(),
             )
         })?;
-        if let Object::Number(num) = arg {
+        if let Some(num) = arg.as_f64() {
             Ok(Object::Number(num.trunc()))
         } else {
             Err(RuntimeError::other(
@@ -594,8 +605,8 @@ impl Callable for TruncFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for TruncFn {
@@ -616,7 +627,7 @@ impl Callable for DegreesFn {
                 "not enough arguments in function".to_string() + &self.to_string(),
             )
         })?;
-        if let Object::Number(num) = arg {
+        if let Some(num) = arg.as_f64() {
             Ok(Object::Number(num.to_degrees()))
         } else {
             Err(RuntimeError::other(
@@ -625,8 +636,8 @@ impl Callable for DegreesFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for DegreesFn {
@@ -647,7 +658,7 @@ impl Callable for RadiansFn {
                 "not enough arguments in function".to_string() + &self.to_string(),
             )
         })?;
-        if let Object::Number(num) = arg {
+        if let Some(num) = arg.as_f64() {
             Ok(Object::Number(num.to_radians()))
         } else {
             Err(RuntimeError::other(
@@ -656,8 +667,8 @@ impl Callable for RadiansFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for RadiansFn {
@@ -674,10 +685,10 @@ impl Callable for MinFn {
     ) -> Result<Object, RuntimeError> {
         let mut min: Option<f64> = None;
         for arg in &_arguments {
-            if let Object::Number(num) = arg {
+            if let Some(num) = arg.as_f64() {
                 min = Some(match min {
-                    Some(m) => m.min(*num),
-                    None => *num,
+                    Some(m) => m.min(num),
+                    None => num,
                 });
             } else {
                 return Err(RuntimeError::other(
@@ -689,8 +700,8 @@ impl Callable for MinFn {
         min.map(Object::Number)
             .ok_or_else(|| RuntimeError::other(0, "no arguments provided".to_string()))
     }
-    fn arity(&self) -> usize {
-        2
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
     }
 }
 impl Display for MinFn {
@@ -707,10 +718,10 @@ impl Callable for MaxFn {
     ) -> Result<Object, RuntimeError> {
         let mut max: Option<f64> = None;
         for arg in &_arguments {
-            if let Object::Number(num) = arg {
+            if let Some(num) = arg.as_f64() {
                 max = Some(match max {
-                    Some(m) => m.max(*num),
-                    None => *num,
+                    Some(m) => m.max(num),
+                    None => num,
                 });
             } else {
                 return Err(RuntimeError::other(
@@ -722,8 +733,8 @@ impl Callable for MaxFn {
         max.map(Object::Number)
             .ok_or_else(|| RuntimeError::other(0, "no arguments provided".to_string()))
     }
-    fn arity(&self) -> usize {
-        2
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
     }
 }
 impl Display for MaxFn {
@@ -747,8 +758,8 @@ impl Callable for ClampFn {
         let value = &_arguments[_arguments.len() - 3];
         let min = &_arguments[_arguments.len() - 2];
         let max = &_arguments[_arguments.len() - 1];
-        if let (Object::Number(v), Object::Number(minv), Object::Number(maxv)) = (value, min, max) {
-            Ok(Object::Number(v.max(*minv).min(*maxv)))
+        if let (Some(v), Some(minv), Some(maxv)) = (value.as_f64(), min.as_f64(), max.as_f64()) {
+            Ok(Object::Number(v.max(minv).min(maxv)))
         } else {
             Err(RuntimeError::other(
                 0,
@@ -756,8 +767,8 @@ impl Callable for ClampFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        3
+    fn arity(&self) -> RangeInclusive<usize> {
+        3..=3
     }
 }
 impl Display for ClampFn {
@@ -779,8 +790,8 @@ impl Callable for RandomFn {
             )
         })?;
 
-        if let Object::Number(max) = range_obj {
-            if *max <= 0.0 {
+        if let Some(max) = range_obj.as_f64() {
+            if max <= 0.0 {
                 return Err(RuntimeError::other(0, "range must be positive".to_string()));
             }
             let rand_value = Self::lcg_next();
@@ -793,8 +804,8 @@ impl Callable for RandomFn {
             ))
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for RandomFn {
@@ -802,6 +813,80 @@ impl Display for RandomFn {
         write!(f, "<native fn random>")
     }
 }
+impl Callable for IntFn {
+    fn call(
+        &self,
+        _interpreter: &mut Interpreter,
+        _arguments: Vec<Object>,
+    ) -> Result<Object, RuntimeError> {
+        let arg = _arguments.last().ok_or_else(|| {
+            RuntimeError::other(
+                0,
+                "not enough arguments in function".to_string() + &self.to_string(),
+            )
+        })?;
+        match arg {
+            Object::Integer(int) => Ok(Object::Integer(*int)),
+            // truncates towards zero like a cast
+            Object::Number(num) => float_to_integer(num.trunc())
+                .map(Object::Integer)
+                .ok_or_else(|| {
+                    RuntimeError::other(0, format!("{} cannot be converted to an integer", num))
+                }),
+            Object::String(string) => string.trim().parse().map(Object::Integer).map_err(|_| {
+                RuntimeError::other(0, format!("'{}' is not a valid integer", string))
+            }),
+            _ => Err(RuntimeError::other(
+                0,
+                "argument must be a number or a string".to_string(),
+            )),
+        }
+    }
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
+    }
+}
+impl Display for IntFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<native fn int>")
+    }
+}
+
+impl Callable for FloatFn {
+    fn call(
+        &self,
+        _interpreter: &mut Interpreter,
+        _arguments: Vec<Object>,
+    ) -> Result<Object, RuntimeError> {
+        let arg = _arguments.last().ok_or_else(|| {
+            RuntimeError::other(
+                0,
+                "not enough arguments in function".to_string() + &self.to_string(),
+            )
+        })?;
+        match arg {
+            Object::Integer(_) | Object::Number(_) => Ok(Object::Number(arg.as_f64().unwrap())),
+            Object::String(string) => {
+                string.trim().parse().map(Object::Number).map_err(|_| {
+                    RuntimeError::other(0, format!("'{}' is not a valid number", string))
+                })
+            }
+            _ => Err(RuntimeError::other(
+                0,
+                "argument must be a number or a string".to_string(),
+            )),
+        }
+    }
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
+    }
+}
+impl Display for FloatFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<native fn float>")
+    }
+}
+
 thread_local! {
     static RNG_STATE: RefCell<u64> = RefCell::new(seed_from_time());
 }
@@ -827,6 +912,14 @@ impl RandomFn {
 pub fn create_class() -> ClassObject {
     let methods = HashMap::new();
     let mut static_methods = HashMap::new();
+    static_methods.insert(
+        "int".to_string(),
+        Rc::new(RefCell::new(Box::new(IntFn) as Box<dyn Callable>)),
+    );
+    static_methods.insert(
+        "float".to_string(),
+        Rc::new(RefCell::new(Box::new(FloatFn) as Box<dyn Callable>)),
+    );
     static_methods.insert(
         "abs".to_string(),
         Rc::new(RefCell::new(Box::new(AbsFn) as Box<dyn Callable>)),
@@ -924,6 +1017,8 @@ pub fn create_class() -> ClassObject {
         name: "Math".to_string(),
         superclass: None,
         methods,
+        setters: HashMap::new(),
         static_methods,
+        static_fields: Rc::new(RefCell::new(HashMap::new())),
     }
 }
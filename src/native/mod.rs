@@ -1,6 +1,10 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod io;
 #[cfg(not(target_arch = "wasm32"))]
+mod list;
+#[cfg(not(target_arch = "wasm32"))]
+mod map;
+#[cfg(not(target_arch = "wasm32"))]
 mod math;
 #[cfg(not(target_arch = "wasm32"))]
 mod native_tests;
@@ -12,6 +16,7 @@ mod string;
 mod system;
 #[cfg(not(target_arch = "wasm32"))]
 mod time;
+mod types;
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -21,11 +26,24 @@ use crate::object::Object;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub fn add_native_functions(globals: &Rc<RefCell<Environment>>) {
+    globals
+        .borrow_mut()
+        .define("type".to_string(), types::create_type_fn())
+        .expect("Failed to define type function");
+
     globals
         .borrow_mut()
         .define("IO".to_string(), Object::Class(io::create_class()))
         .expect("Failed to define IO class");
 
+    globals
+        .borrow_mut()
+        .define("List".to_string(), Object::Class(list::create_class()))
+        .expect("Failed to define List class");
+    globals
+        .borrow_mut()
+        .define("Map".to_string(), Object::Class(map::create_class()))
+        .expect("Failed to define Map class");
     globals
         .borrow_mut()
         .define("Math".to_string(), Object::Class(math::create_class()))
@@ -52,6 +70,10 @@ pub fn add_native_functions(globals: &Rc<RefCell<Environment>>) {
 }
 
 #[cfg(target_arch = "wasm32")]
-pub fn add_native_functions(_globals: &Rc<RefCell<Environment>>) {
-    // No native functions available in WASM
+pub fn add_native_functions(globals: &Rc<RefCell<Environment>>) {
+    // Only the functions that don't need the host system are available in WASM
+    globals
+        .borrow_mut()
+        .define("type".to_string(), types::create_type_fn())
+        .expect("Failed to define type function");
 }
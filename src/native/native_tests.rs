@@ -226,7 +226,7 @@ mod test {
         let source = r#"
             print String.len("hello");
             print String.split("a,b,c", ",");
-            print String.join("-", "a,b,c");
+            print String.join("-", ["a", "b", "c"]);
             print String.replace("hello world", "world", "rust");
             print String.lower("HeLLo");
             print String.upper("HeLLo");
@@ -268,7 +268,7 @@ mod test {
 
         let mut lines = result.lines();
         assert_eq!(lines.next().unwrap().trim(), "5"); // len
-        assert_eq!(lines.next().unwrap().trim(), "a,b,c"); // split
+        assert_eq!(lines.next().unwrap().trim(), "[a, b, c]"); // split
         assert_eq!(lines.next().unwrap().trim(), "a-b-c"); // join
         assert_eq!(lines.next().unwrap().trim(), "hello rust"); // replace
         assert_eq!(lines.next().unwrap().trim(), "hello"); // lower
@@ -282,6 +282,101 @@ mod test {
         assert_eq!(lines.next().unwrap().trim(), "false"); // contains not found
     }
 
+    #[test]
+    fn test_list_functions() {
+        let source = r#"
+            var xs = [1, 2];
+            List.push(xs, 3);
+            print xs;
+            print List.len(xs);
+            print List.pop(xs);
+            print xs;
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        let mut lines = result.lines();
+        assert_eq!(lines.next().unwrap().trim(), "[1, 2, 3]"); // push
+        assert_eq!(lines.next().unwrap().trim(), "3"); // len
+        assert_eq!(lines.next().unwrap().trim(), "3"); // pop
+        assert_eq!(lines.next().unwrap().trim(), "[1, 2]"); // list after pop
+    }
+
+    #[test]
+    fn test_map_functions() {
+        let source = r#"
+            var m = {"a": 1, "b": 2};
+            print Map.len(m);
+            print Map.has(m, "a");
+            print Map.delete(m, "a");
+            print Map.has(m, "a");
+            print Map.delete(m, "a");
+            print Map.keys(m);
+            print Map.values(m);
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        let mut lines = result.lines();
+        assert_eq!(lines.next().unwrap().trim(), "2"); // len
+        assert_eq!(lines.next().unwrap().trim(), "true"); // has
+        assert_eq!(lines.next().unwrap().trim(), "1"); // delete
+        assert_eq!(lines.next().unwrap().trim(), "false"); // has after delete
+        assert_eq!(lines.next().unwrap().trim(), "Nil"); // delete missing key
+        assert_eq!(lines.next().unwrap().trim(), "[b]"); // keys
+        assert_eq!(lines.next().unwrap().trim(), "[2]"); // values
+    }
+
     #[test]
     fn test_system_functions() {
         let source = r#"
@@ -322,7 +417,7 @@ mod test {
         let platform = lines.next().unwrap().trim();
         assert!(platform == "linux" || platform == "windows" || platform == "macos");
         assert_eq!(lines.next().unwrap().trim(), "true"); // env PATH exists
-        assert_eq!(lines.next().unwrap().trim(), "true"); // args returns string
+        assert_eq!(lines.next().unwrap().trim(), "true"); // args returns list
         assert_eq!(lines.next().unwrap().trim(), "hi"); // exec echo hi
     }
 
@@ -366,4 +461,46 @@ mod test {
         assert_eq!(lines.next().unwrap().trim(), "true"); // t > 0
         assert_eq!(lines.next().unwrap().trim(), "slept");
     }
+
+    #[test]
+    fn test_type_function() {
+        let source = r#"
+            class Point {}
+            print type(1); print type(2.5); print type("s"); print type(nil);
+            print type(true); print type(type); print type(Point); print type(Point());
+            print type([1]); print type({"a": 1}); print type(0..2);
+        "#;
+
+        let tokens = tokenize(source).expect("Tokenization failed");
+        let mut stmts = parse(tokens).expect("Parsing failed");
+
+        let output: Rc<RefCell<Vec<u8>>> = Rc::new(RefCell::new(Vec::new()));
+        let output_for_interp = output.clone();
+
+        let mut interpreter = Interpreter::new_with_output(output_for_interp);
+        let mut resolver = Resolver::new(&mut interpreter);
+        resolver.resolve(&mut stmts).expect("Resolving failed");
+
+        let interpreter_result = interpreter.interprete(&mut stmts);
+        assert!(
+            interpreter_result.is_ok(),
+            "Interpreter failed: {:?}",
+            interpreter_result.err()
+        );
+        match interpreter_result {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        };
+
+        let bytes: std::cell::Ref<'_, Vec<u8>> = output.borrow();
+        let result = match str::from_utf8(&bytes) {
+            Ok(v) => v.to_owned(),
+            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
+        };
+
+        assert_eq!(
+            result.split_whitespace().collect::<Vec<_>>().join(" "),
+            "number number string nil boolean function class Point list map range"
+        );
+    }
 }
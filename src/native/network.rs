@@ -6,6 +6,7 @@ use crate::runtime_error::RuntimeError;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::ops::RangeInclusive;
 use std::rc::Rc;
 
 #[derive(Debug, Clone)]
@@ -40,8 +41,8 @@ impl Callable for HttpGetFn {
             Err(e) => Err(RuntimeError::other(0, format!("http_get: request failed: {}", e))),
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for HttpGetFn {
@@ -78,8 +79,8 @@ impl Callable for HttpPostFn {
             Err(e) => Err(RuntimeError::other(0, format!("http_post: request failed: {}", e))),
         }
     }
-    fn arity(&self) -> usize {
-        2
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
     }
 }
 impl Display for HttpPostFn {
@@ -119,8 +120,8 @@ impl Callable for DownloadFileFn {
             Err(e) => Err(RuntimeError::other(0, format!("download_file: request failed: {}", e))),
         }
     }
-    fn arity(&self) -> usize {
-        2
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
     }
 }
 impl Display for DownloadFileFn {
@@ -151,8 +152,8 @@ impl Callable for PingFn {
             Err(_) => Ok(Object::Boolean(false)),
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for PingFn {
@@ -184,7 +185,9 @@ pub fn create_class() -> ClassObject {
         name: "Network".to_string(),
         superclass: None,
         methods,
+        setters: HashMap::new(),
         static_methods,
+        static_fields: Rc::new(RefCell::new(HashMap::new())),
     }
 }
 
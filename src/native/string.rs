@@ -6,6 +6,7 @@ use crate::runtime_error::RuntimeError;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::ops::RangeInclusive;
 use std::rc::Rc;
 
 #[derive(Debug, Clone)]
@@ -44,10 +45,10 @@ impl Callable for LenFn {
             Object::String(s) => s,
             _ => return Err(RuntimeError::argument_error(0, "len(s): argument must be a string")),
         };
-        Ok(Object::Number(s.chars().count() as f64))
+        Ok(Object::Integer(s.chars().count() as i64))
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for LenFn {
@@ -73,10 +74,14 @@ impl Callable for SplitFn {
             Object::String(s) => s,
             _ => return Err(RuntimeError::argument_error(0, "split(s, sep): second argument must be a string")),
         };
-        Ok(Object::String(s.split(sep).collect::<Vec<_>>().join(",")))
+        Ok(Object::new_list(
+            s.split(sep.as_str())
+                .map(|part| Object::String(part.to_string()))
+                .collect(),
+        ))
     }
-    fn arity(&self) -> usize {
-        2
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
     }
 }
 impl Display for SplitFn {
@@ -88,7 +93,7 @@ impl Display for SplitFn {
 impl Callable for JoinFn {
     fn call(
         &self,
-        _interpreter: &mut Interpreter,
+        interpreter: &mut Interpreter,
         _arguments: Vec<Object>,
     ) -> Result<Object, RuntimeError> {
         if _arguments.len() != 2 {
@@ -99,14 +104,20 @@ impl Callable for JoinFn {
             _ => return Err(RuntimeError::argument_error(0, "join(sep, items): first argument must be a string")),
         };
         let items = match &_arguments[1] {
-            Object::String(s) => s,
-            _ => return Err(RuntimeError::argument_error(0, "join(sep, items): second argument must be a comma-separated string")),
+            Object::List(items) => items,
+            _ => return Err(RuntimeError::argument_error(0, "join(sep, items): second argument must be a list")),
         };
-        let joined = items.split(',').collect::<Vec<_>>().join(sep);
+        // items may run `__str__`, which could change the list while it is borrowed
+        let items = items.borrow().clone();
+        let joined = items
+            .iter()
+            .map(|item| interpreter.stringify(item))
+            .collect::<Result<Vec<_>, _>>()?
+            .join(sep);
         Ok(Object::String(joined))
     }
-    fn arity(&self) -> usize {
-        2
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
     }
 }
 impl Display for JoinFn {
@@ -138,8 +149,8 @@ impl Callable for ReplaceFn {
         };
         Ok(Object::String(s.replace(from, to)))
     }
-    fn arity(&self) -> usize {
-        3
+    fn arity(&self) -> RangeInclusive<usize> {
+        3..=3
     }
 }
 impl Display for ReplaceFn {
@@ -163,8 +174,8 @@ impl Callable for LowerFn {
         };
         Ok(Object::String(s.to_lowercase()))
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for LowerFn {
@@ -188,8 +199,8 @@ impl Callable for UpperFn {
         };
         Ok(Object::String(s.to_uppercase()))
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for UpperFn {
@@ -213,8 +224,8 @@ impl Callable for StripFn {
         };
         Ok(Object::String(s.trim().to_string()))
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for StripFn {
@@ -242,8 +253,8 @@ impl Callable for StartsWithFn {
         };
         Ok(Object::Boolean(s.starts_with(prefix)))
     }
-    fn arity(&self) -> usize {
-        2
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
     }
 }
 impl Display for StartsWithFn {
@@ -271,8 +282,8 @@ impl Callable for EndsWithFn {
         };
         Ok(Object::Boolean(s.ends_with(suffix)))
     }
-    fn arity(&self) -> usize {
-        2
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
     }
 }
 impl Display for EndsWithFn {
@@ -299,12 +310,12 @@ impl Callable for FindFn {
             _ => return Err(RuntimeError::argument_error(0, "find(s, sub): second argument must be a string")),
         };
         match s.find(sub) {
-            Some(idx) => Ok(Object::Number(idx as f64)),
+            Some(idx) => Ok(Object::Integer(idx as i64)),
             None => Ok(Object::Nil),
         }
     }
-    fn arity(&self) -> usize {
-        2
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
     }
 }
 impl Display for FindFn {
@@ -332,8 +343,8 @@ impl Callable for ContainsFn {
         };
         Ok(Object::Boolean(s.contains(sub)))
     }
-    fn arity(&self) -> usize {
-        2
+    fn arity(&self) -> RangeInclusive<usize> {
+        2..=2
     }
 }
 impl Display for ContainsFn {
@@ -393,7 +404,9 @@ pub fn create_class() -> ClassObject {
         name: "String".to_string(),
         superclass: None,
         methods,
+        setters: HashMap::new(),
         static_methods,
+        static_fields: Rc::new(RefCell::new(HashMap::new())),
     }
 }
 
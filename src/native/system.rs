@@ -6,6 +6,7 @@ use crate::runtime_error::RuntimeError;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::ops::RangeInclusive;
 use std::rc::Rc;
 
 #[derive(Debug, Clone)]
@@ -30,12 +31,13 @@ impl Callable for ExitFn {
         }
         let code = match &_arguments[0] {
             Object::Number(n) => *n as i32,
+            Object::Integer(n) => *n as i32,
             _ => return Err(RuntimeError::argument_error(0, "exit(code): argument must be a number")),
         };
         std::process::exit(code);
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for ExitFn {
@@ -62,8 +64,8 @@ impl Callable for EnvFn {
             Err(_) => Ok(Object::Nil),
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for EnvFn {
@@ -78,11 +80,11 @@ impl Callable for ArgsFn {
         _interpreter: &mut Interpreter,
         _arguments: Vec<Object>,
     ) -> Result<Object, RuntimeError> {
-        let args: Vec<String> = std::env::args().collect();
-        Ok(Object::String(args.join(",")))
+        let args = std::env::args().map(Object::String).collect();
+        Ok(Object::new_list(args))
     }
-    fn arity(&self) -> usize {
-        0
+    fn arity(&self) -> RangeInclusive<usize> {
+        0..=0
     }
 }
 impl Display for ArgsFn {
@@ -112,8 +114,8 @@ impl Callable for ExecFn {
             Err(e) => Err(RuntimeError::other(0, format!("Exec error: {e}"))),
         }
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for ExecFn {
@@ -130,8 +132,8 @@ impl Callable for PlatformFn {
     ) -> Result<Object, RuntimeError> {
         Ok(Object::String(std::env::consts::OS.to_string()))
     }
-    fn arity(&self) -> usize {
-        0
+    fn arity(&self) -> RangeInclusive<usize> {
+        0..=0
     }
 }
 impl Display for PlatformFn {
@@ -167,7 +169,9 @@ pub fn create_class() -> ClassObject {
         name: "System".to_string(),
         superclass: None,
         methods,
+        setters: HashMap::new(),
         static_methods,
+        static_fields: Rc::new(RefCell::new(HashMap::new())),
     }
 }
 
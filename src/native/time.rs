@@ -6,6 +6,7 @@ use crate::runtime_error::RuntimeError;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::ops::RangeInclusive;
 use std::rc::Rc;
 
 #[derive(Debug, Clone)]
@@ -25,8 +26,8 @@ impl Callable for TimeFn {
             .as_secs_f64();
         Ok(Object::Number(now))
     }
-    fn arity(&self) -> usize {
-        0
+    fn arity(&self) -> RangeInclusive<usize> {
+        0..=0
     }
 }
 impl Display for TimeFn {
@@ -49,6 +50,7 @@ impl Callable for SleepFn {
         }
         let secs = match &_arguments[0] {
             Object::Number(n) => *n,
+            Object::Integer(n) => *n as f64,
             _ => {
                 return Err(RuntimeError::argument_error(
                     0,
@@ -65,8 +67,8 @@ impl Callable for SleepFn {
         std::thread::sleep(std::time::Duration::from_secs_f64(secs));
         Ok(Object::Nil)
     }
-    fn arity(&self) -> usize {
-        1
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
     }
 }
 impl Display for SleepFn {
@@ -91,6 +93,8 @@ pub fn create_class() -> ClassObject {
         name: "Time".to_string(),
         superclass: None,
         methods,
+        setters: HashMap::new(),
         static_methods,
+        static_fields: Rc::new(RefCell::new(HashMap::new())),
     }
 }
\ No newline at end of file
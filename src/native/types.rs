@@ -0,0 +1,35 @@
+use crate::callable::Callable;
+use crate::interpreter::Interpreter;
+use crate::object::Object;
+use crate::runtime_error::RuntimeError;
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
+#[derive(Debug, Clone)]
+struct TypeFn;
+
+impl Callable for TypeFn {
+    fn call(
+        &self,
+        _interpreter: &mut Interpreter,
+        arguments: Vec<Object>,
+    ) -> Result<Object, RuntimeError> {
+        Ok(Object::String(arguments[0].type_name()))
+    }
+    fn arity(&self) -> RangeInclusive<usize> {
+        1..=1
+    }
+}
+impl Display for TypeFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<native fn type>")
+    }
+}
+
+/// Creates the global `type(value)` function, which returns the name of the type of
+/// its argument.
+pub fn create_type_fn() -> Object {
+    Object::Callable(Rc::new(RefCell::new(Box::new(TypeFn) as Box<dyn Callable>)))
+}
@@ -1,14 +1,17 @@
 use crate::{
     callable::Callable, // changed: import trait only
     class::{ClassInstance, ClassObject},
+    module::Module,
+    traits::Trait,
 };
-use std::rc::Rc;
 use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
 
 /// Represents the different types of values that can be produced and manipulated by the interpreter.
 ///
 /// These are the runtime values of the language, such as the absence of a value (`Nil`),
-/// boolean true/false, floating-point numbers, and strings.
+/// boolean true/false, integers, floating-point numbers, and strings.
 #[derive(Clone)]
 pub enum Object {
     /// Represents the absence of a value, similar to `null` in other languages.
@@ -17,12 +20,224 @@ pub enum Object {
     Boolean(bool),
     /// Represents a floating-point number.
     Number(f64),
+    /// Represents a 64-bit signed integer, produced by integer literals.
+    Integer(i64),
     /// Represents a text string.
     String(String),
     /// Closure or function
     Callable(Rc<RefCell<Box<dyn Callable>>>), // changed: use trait object
     Class(ClassObject),
     ClassInstance(ClassInstance),
+    /// A mutable list of values, shared by reference like class instances.
+    List(Rc<RefCell<Vec<Object>>>),
+    /// A mutable hash map from keys to values, shared by reference like lists.
+    Map(Rc<RefCell<HashMap<MapKey, Object>>>),
+    /// A module loaded by an `import` statement.
+    Module(Rc<Module>),
+    /// A trait, whose methods classes can mix in with `with`.
+    Trait(Rc<Trait>),
+    /// A range of integers from the start up to the end, which is only included if the
+    /// flag is set (`0..=10`).
+    Range(i64, i64, bool),
+}
+
+/// A hashable key of an `Object::Map`.
+///
+/// Only strings, numbers, booleans and `nil` can be used as keys. Floats with an
+/// integral value are stored as integers, so `m[1]` and `m[1.0]` address the same
+/// entry; all other floats are stored by their bit pattern.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MapKey {
+    Nil,
+    Boolean(bool),
+    Integer(i64),
+    Number(u64),
+    String(String),
+}
+
+impl MapKey {
+    /// Converts a value into a map key, or returns `None` if the value is not hashable.
+    pub fn from_object(object: &Object) -> Option<MapKey> {
+        match object {
+            Object::Nil => Some(MapKey::Nil),
+            Object::Boolean(b) => Some(MapKey::Boolean(*b)),
+            Object::Integer(i) => Some(MapKey::Integer(*i)),
+            Object::Number(n) => match float_to_integer(*n) {
+                Some(i) => Some(MapKey::Integer(i)),
+                None => Some(MapKey::Number(n.to_bits())),
+            },
+            Object::String(s) => Some(MapKey::String(s.clone())),
+            _ => None,
+        }
+    }
+
+    /// Converts the key back into the value it was created from.
+    pub fn to_object(&self) -> Object {
+        match self {
+            MapKey::Nil => Object::Nil,
+            MapKey::Boolean(b) => Object::Boolean(*b),
+            MapKey::Integer(i) => Object::Integer(*i),
+            MapKey::Number(bits) => Object::Number(f64::from_bits(*bits)),
+            MapKey::String(s) => Object::String(s.clone()),
+        }
+    }
+}
+
+impl Object {
+    /// Wraps the given elements into a new `Object::List`.
+    pub fn new_list(elements: Vec<Object>) -> Object {
+        Object::List(Rc::new(RefCell::new(elements)))
+    }
+
+    /// Wraps the given entries into a new `Object::Map`.
+    pub fn new_map(entries: HashMap<MapKey, Object>) -> Object {
+        Object::Map(Rc::new(RefCell::new(entries)))
+    }
+
+    /// Returns whether both values are the same object, as checked by the `is` operator.
+    ///
+    /// Lists, maps, instances, callables, classes and modules are compared by reference,
+    /// all other values by their value.
+    pub fn is_identical(&self, other: &Object) -> bool {
+        match (self, other) {
+            (Object::List(a), Object::List(b)) => Rc::ptr_eq(a, b),
+            (Object::Map(a), Object::Map(b)) => Rc::ptr_eq(a, b),
+            _ => self == other,
+        }
+    }
+
+    /// Returns the name of the value's type, as returned by `type()` and used in error
+    /// messages. Instances are named after their class.
+    pub fn type_name(&self) -> String {
+        match self {
+            Object::Nil => "nil".to_string(),
+            Object::Boolean(_) => "boolean".to_string(),
+            Object::Number(_) | Object::Integer(_) => "number".to_string(),
+            Object::String(_) => "string".to_string(),
+            Object::Callable(_) => "function".to_string(),
+            Object::Class(_) => "class".to_string(),
+            Object::ClassInstance(instance) => instance.class().name.clone(),
+            Object::List(_) => "list".to_string(),
+            Object::Map(_) => "map".to_string(),
+            Object::Module(_) => "module".to_string(),
+            Object::Trait(_) => "trait".to_string(),
+            Object::Range(..) => "range".to_string(),
+        }
+    }
+
+    /// Returns the address of a list or map, used to detect containers that contain
+    /// themselves while printing or comparing them.
+    pub fn container_ptr(&self) -> Option<*const ()> {
+        match self {
+            Object::List(list) => Some(Rc::as_ptr(list) as *const ()),
+            Object::Map(map) => Some(Rc::as_ptr(map) as *const ()),
+            _ => None,
+        }
+    }
+
+    /// Formats the value, printing `[...]` or `{...}` for a list or map that is already
+    /// being printed further up in `seen`.
+    fn fmt_nested(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        seen: &mut Vec<*const ()>,
+    ) -> std::fmt::Result {
+        let Some(ptr) = self.container_ptr() else {
+            return write!(f, "{}", self);
+        };
+        if seen.contains(&ptr) {
+            return match self {
+                Object::List(_) => write!(f, "[...]"),
+                _ => write!(f, "{{...}}"),
+            };
+        }
+
+        seen.push(ptr);
+        let result = self.fmt_elements(f, seen);
+        seen.pop();
+        result
+    }
+
+    /// Formats the elements of a list or the entries of a map, see `fmt_nested`.
+    fn fmt_elements(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        seen: &mut Vec<*const ()>,
+    ) -> std::fmt::Result {
+        match self {
+            Object::List(list) => {
+                write!(f, "[")?;
+                for (i, element) in list.borrow().iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    element.fmt_nested(f, seen)?;
+                }
+                write!(f, "]")
+            }
+            Object::Map(map) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in map.borrow().iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: ", key.to_object())?;
+                    value.fmt_nested(f, seen)?;
+                }
+                write!(f, "}}")
+            }
+            _ => write!(f, "{}", self),
+        }
+    }
+
+    /// Compares two values, treating a pair of lists or maps that is already being compared
+    /// further up in `seen` as equal, so containers that contain themselves can be compared.
+    fn eq_nested(&self, other: &Object, seen: &mut Vec<(*const (), *const ())>) -> bool {
+        let (Some(a), Some(b)) = (self.container_ptr(), other.container_ptr()) else {
+            return self == other;
+        };
+        if a == b || seen.contains(&(a, b)) {
+            return true;
+        }
+
+        seen.push((a, b));
+        let equal = match (self, other) {
+            (Object::List(a), Object::List(b)) => {
+                let (a, b) = (a.borrow(), b.borrow());
+                a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| a.eq_nested(b, seen))
+            }
+            (Object::Map(a), Object::Map(b)) => {
+                let (a, b) = (a.borrow(), b.borrow());
+                a.len() == b.len()
+                    && a.iter()
+                        .all(|(key, a)| b.get(key).is_some_and(|b| a.eq_nested(b, seen)))
+            }
+            _ => false,
+        };
+        seen.pop();
+        equal
+    }
+
+    /// Returns the value of a number as a float, promoting integers.
+    ///
+    /// Returns `None` if the object is not a number.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Object::Number(n) => Some(*n),
+            Object::Integer(i) => Some(*i as f64),
+            _ => None,
+        }
+    }
+}
+
+/// Converts a float into an integer if it has no fractional part and fits into an `i64`.
+pub fn float_to_integer(n: f64) -> Option<i64> {
+    // i64::MAX is not representable as f64, so the upper bound is exclusive at 2^63
+    if n.fract() == 0.0 && n >= i64::MIN as f64 && n < i64::MAX as f64 {
+        Some(n as i64)
+    } else {
+        None
+    }
 }
 
 impl std::fmt::Debug for Object {
@@ -31,10 +246,19 @@ impl std::fmt::Debug for Object {
             Object::Nil => write!(f, "Nil"),
             Object::Boolean(b) => write!(f, "Boolean({})", b),
             Object::Number(n) => write!(f, "Number({})", n),
+            Object::Integer(i) => write!(f, "Integer({})", i),
             Object::String(s) => write!(f, "String({:?})", s),
             Object::Callable(_) => write!(f, "Callable(<dyn Callable>)"),
             Object::Class(class) => write!(f, "Class({})", class.name),
             Object::ClassInstance(instance) => write!(f, "ClassInstance({:?})", instance),
+            Object::List(list) => write!(f, "List({:?})", list.borrow()),
+            Object::Map(map) => write!(f, "Map({:?})", map.borrow()),
+            Object::Module(module) => write!(f, "Module({})", module.name),
+            Object::Trait(t) => write!(f, "Trait({})", t.name),
+            Object::Range(start, end, inclusive) => {
+                let operator = if *inclusive { "..=" } else { ".." };
+                write!(f, "Range({}{}{})", start, operator, end)
+            }
         }
     }
 }
@@ -45,10 +269,18 @@ impl std::fmt::Display for Object {
             Object::Nil => write!(f, "Nil"),
             Object::Boolean(bool) => write!(f, "{}", bool),
             Object::Number(num) => write!(f, "{}", num),
+            Object::Integer(int) => write!(f, "{}", int),
             Object::String(string) => write!(f, "{}", string),
             Object::Callable(callable) => write!(f, "{}", callable.borrow()), // changed
             Object::Class(class) => write!(f, "{}", class),
             Object::ClassInstance(instance) => write!(f, "{}", instance),
+            Object::List(_) | Object::Map(_) => self.fmt_nested(f, &mut Vec::new()),
+            Object::Module(module) => write!(f, "{}", module),
+            Object::Trait(t) => write!(f, "{}", t),
+            Object::Range(start, end, inclusive) => {
+                let operator = if *inclusive { "..=" } else { ".." };
+                write!(f, "{}{}{}", start, operator, end)
+            }
         }
     }
 }
@@ -59,10 +291,29 @@ impl PartialEq for Object {
             (Object::Nil, Object::Nil) => true,
             (Object::Boolean(a), Object::Boolean(b)) => a == b,
             (Object::Number(a), Object::Number(b)) => a == b,
+            (Object::Integer(a), Object::Integer(b)) => a == b,
+            (Object::Integer(a), Object::Number(b)) | (Object::Number(b), Object::Integer(a)) => {
+                float_to_integer(*b) == Some(*a)
+            }
             (Object::String(a), Object::String(b)) => a == b,
-            // callable are just never eq
-            (Object::Callable(_), Object::Callable(_)) => false, // unchanged
-            (Object::Class(_), Object::Class(_)) => false,
+            (Object::Callable(a), Object::Callable(b)) => Rc::ptr_eq(a, b),
+            (Object::Class(a), Object::Class(b)) => a.ptr_eq(b),
+            // instances are only equal to themselves, `==` in scripts also looks at `__eq__`
+            (Object::ClassInstance(a), Object::ClassInstance(b)) => a.ptr_eq(b),
+            (Object::List(_), Object::List(_)) | (Object::Map(_), Object::Map(_)) => {
+                self.eq_nested(other, &mut Vec::new())
+            }
+            (Object::Module(a), Object::Module(b)) => Rc::ptr_eq(a, b),
+            (Object::Trait(a), Object::Trait(b)) => Rc::ptr_eq(a, b),
+            // `0..=5` and `0..6` contain the same numbers
+            (
+                Object::Range(a_start, a_end, a_inclusive),
+                Object::Range(b_start, b_end, b_inclusive),
+            ) => {
+                a_start == b_start
+                    && *a_end as i128 + *a_inclusive as i128
+                        == *b_end as i128 + *b_inclusive as i128
+            }
             _ => false,
         }
     }
@@ -1,7 +1,12 @@
 use crate::expr_types::*;
 use crate::object::Object;
 use crate::parser_error::{self, error, ParseError};
-use crate::stmt_types::{ClassStmt, FunctionStmt, IfStmt, ReturnStmt, Stmt, VarStmt, WhileStmt};
+use crate::scanner::{is_keyword, is_valid_variable_char};
+use crate::stmt_types::{
+    CatchClause, ClassPattern, ClassStmt, ForInStmt, ForStmt, FunctionStmt, IfStmt, ImportStmt,
+    MatchArm, MatchStmt, Param, Pattern, ReturnStmt, Stmt, ThrowStmt, TraitStmt, TryStmt, VarStmt,
+    WhileStmt,
+};
 use crate::token::TokenType;
 use crate::Token;
 
@@ -46,6 +51,51 @@ impl Parser {
         self.assignment()
     }
 
+    /// Parses a conditional expression (`condition ? then_branch : else_branch`).
+    ///
+    /// The conditional operator is right-associative, so `a ? b : c ? d : e`
+    /// is `a ? b : (c ? d : e)`.
+    fn ternary(&mut self) -> Result<Expr, ParseError> {
+        let condition = self.nil_coalescing()?;
+
+        if self.match_token(TokenType::Question) {
+            let question = self.previous().clone();
+            let then_branch = self.expression()?;
+            self.consume(
+                TokenType::Colon,
+                "Expect ':' after then branch of conditional expression.",
+            )?;
+            let else_branch = self.ternary()?;
+
+            return Ok(Expr::Ternary(TernaryExpr {
+                condition: Box::new(condition),
+                question,
+                then_branch: Box::new(then_branch),
+                else_branch: Box::new(else_branch),
+            }));
+        }
+
+        Ok(condition)
+    }
+
+    /// Parses a nil-coalescing expression (`a ?? b`), which evaluates `b` only if `a` is nil.
+    fn nil_coalescing(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.or()?;
+
+        while self.match_token(TokenType::QuestionQuestion) {
+            let operator = self.previous().clone();
+            let right = self.or()?;
+
+            expr = Expr::Logical(LogicalExpr {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            });
+        }
+
+        Ok(expr)
+    }
+
     fn or(&mut self) -> Result<Expr, ParseError> {
         let mut expr = self.and()?;
 
@@ -81,10 +131,18 @@ impl Parser {
     }
 
     fn assignment(&mut self) -> Result<Expr, ParseError> {
-        let expr = self.or()?;
-
-        if self.match_token(TokenType::Equal) {
+        let expr = self.ternary()?;
+
+        if self.match_tokens(&[
+            TokenType::Equal,
+            TokenType::PlusEqual,
+            TokenType::MinusEqual,
+            TokenType::StarEqual,
+            TokenType::SlashEqual,
+            TokenType::PercentEqual,
+        ]) {
             let equals = self.previous().clone();
+            let operator = compound_operator(&equals);
             let value = self.assignment()?;
 
             match expr {
@@ -92,13 +150,24 @@ impl Parser {
                     return Ok(Expr::Assign(AssignExpr {
                         name: var_expr.name,
                         value: Box::new(value),
+                        operator,
                     }))
                 }
-                Expr::PropertyAccess(class_assignment) => {
+                Expr::PropertyAccess(class_assignment) if !class_assignment.optional => {
                     return Ok(Expr::PropertyAssignment(PropertyAssignmentExpr {
                         object: class_assignment.object,
                         name: class_assignment.name,
                         value: Box::new(value),
+                        operator,
+                    }))
+                }
+                Expr::Index(index_expr) => {
+                    return Ok(Expr::IndexAssignment(IndexAssignmentExpr {
+                        object: index_expr.object,
+                        bracket: index_expr.bracket,
+                        index: index_expr.index,
+                        value: Box::new(value),
+                        operator,
                     }))
                 }
                 _ => return Err(error(&equals, "Invalid assignment target.".to_string())),
@@ -108,11 +177,11 @@ impl Parser {
         Ok(expr)
     }
 
-    /// Parses an equality expression (`==`, `!=`).
+    /// Parses an equality expression (`==`, `!=`, `is`).
     ///
     /// This method parses a comparison expression and then checks for
-    /// equality or inequality operators, building binary expression nodes
-    /// as needed.
+    /// equality, inequality or identity operators, building binary expression
+    /// nodes as needed.
     ///
     /// # Returns
     ///
@@ -120,7 +189,7 @@ impl Parser {
     /// expression, or a `ParseError` if an error occurs.
     fn equality(&mut self) -> Result<Expr, ParseError> {
         let mut expr = self.comparison()?;
-        while self.match_tokens(&[TokenType::BangEqual, TokenType::EqualEqual]) {
+        while self.match_tokens(&[TokenType::BangEqual, TokenType::EqualEqual, TokenType::Is]) {
             let operator = self.previous().clone();
             let right = self.comparison().inspect_err(|_| {
                 self.had_error = true;
@@ -135,23 +204,145 @@ impl Parser {
         Ok(expr)
     }
 
-    /// Parses a comparison expression (`>`, `>=`, `<`, `<=`).
+    /// Parses a comparison expression (`>`, `>=`, `<`, `<=`, `instanceof`).
     ///
-    /// This method parses a term and then checks for comparison operators,
-    /// building binary expression nodes as needed.
+    /// This method parses a range expression and then checks for
+    /// comparison operators, building binary expression nodes as needed.
     ///
     /// # Returns
     ///
     /// A `Result` containing an `Expr` representing the parsed comparison
     /// expression, or a `ParseError` if an error occurs.
     fn comparison(&mut self) -> Result<Expr, ParseError> {
-        let mut expr = self.term()?;
+        let mut expr = self.range()?;
         while self.match_tokens(&[
             TokenType::Greater,
             TokenType::GreaterEqual,
             TokenType::Less,
             TokenType::LessEqual,
+            TokenType::Instanceof,
         ]) {
+            let operator = self.previous().clone();
+            let right = self.range().inspect_err(|_| {
+                self.had_error = true;
+                self.synchronize();
+            })?;
+            expr = Expr::Binary(BinaryExpr {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            });
+        }
+        Ok(expr)
+    }
+
+    /// Parses a range expression (`a..b` or `a..=b`).
+    ///
+    /// Ranges don't chain, so at most one range operator is parsed.
+    fn range(&mut self) -> Result<Expr, ParseError> {
+        let expr = self.bitwise_or()?;
+        if self.match_tokens(&[TokenType::DotDot, TokenType::DotDotEqual]) {
+            let operator = self.previous().clone();
+            let right = self.bitwise_or()?;
+            return Ok(Expr::Binary(BinaryExpr {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            }));
+        }
+        Ok(expr)
+    }
+
+    /// Parses a bitwise OR expression (`|`).
+    ///
+    /// This method parses a bitwise XOR expression and then checks for the operator,
+    /// building binary expression nodes as needed.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Expr` representing the parsed bitwise OR expression, or a
+    /// `ParseError` if an error occurs.
+    fn bitwise_or(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.bitwise_xor()?;
+        while self.match_tokens(&[TokenType::BitwiseOr]) {
+            let operator = self.previous().clone();
+            let right = self.bitwise_xor().inspect_err(|_| {
+                self.had_error = true;
+                self.synchronize();
+            })?;
+            expr = Expr::Binary(BinaryExpr {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            });
+        }
+        Ok(expr)
+    }
+
+    /// Parses a bitwise XOR expression (`^`).
+    ///
+    /// This method parses a bitwise AND expression and then checks for the operator,
+    /// building binary expression nodes as needed.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Expr` representing the parsed bitwise XOR expression, or a
+    /// `ParseError` if an error occurs.
+    fn bitwise_xor(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.bitwise_and()?;
+        while self.match_tokens(&[TokenType::BitwiseXor]) {
+            let operator = self.previous().clone();
+            let right = self.bitwise_and().inspect_err(|_| {
+                self.had_error = true;
+                self.synchronize();
+            })?;
+            expr = Expr::Binary(BinaryExpr {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            });
+        }
+        Ok(expr)
+    }
+
+    /// Parses a bitwise AND expression (`&`).
+    ///
+    /// This method parses a shift expression and then checks for the operator,
+    /// building binary expression nodes as needed.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Expr` representing the parsed bitwise AND expression, or a
+    /// `ParseError` if an error occurs.
+    fn bitwise_and(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.shift()?;
+        while self.match_tokens(&[TokenType::BitwiseAnd]) {
+            let operator = self.previous().clone();
+            let right = self.shift().inspect_err(|_| {
+                self.had_error = true;
+                self.synchronize();
+            })?;
+            expr = Expr::Binary(BinaryExpr {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            });
+        }
+        Ok(expr)
+    }
+
+    /// Parses a shift expression (`<<`, `>>`).
+    ///
+    /// This method parses a term and then checks for the operator,
+    /// building binary expression nodes as needed.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Expr` representing the parsed shift expression, or a
+    /// `ParseError` if an error occurs.
+    fn shift(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.term()?;
+        while self.match_tokens(&[TokenType::ShiftLeft, TokenType::ShiftRight]) {
             let operator = self.previous().clone();
             let right = self.term().inspect_err(|_| {
                 self.had_error = true;
@@ -192,7 +383,7 @@ impl Parser {
         Ok(expr)
     }
 
-    /// Parses a multiplicative expression (`*`, `/`).
+    /// Parses a multiplicative expression (`*`, `/`, `%`, `~/`).
     ///
     /// This method parses a unary expression and then checks for multiplicative
     /// operators, building binary expression nodes as needed.
@@ -203,7 +394,12 @@ impl Parser {
     /// expression, or a `ParseError` if an error occurs.
     fn factor(&mut self) -> Result<Expr, ParseError> {
         let mut expr = self.unary()?;
-        while self.match_tokens(&[TokenType::Slash, TokenType::Asterisk]) {
+        while self.match_tokens(&[
+            TokenType::Slash,
+            TokenType::Asterisk,
+            TokenType::Percent,
+            TokenType::TildeSlash,
+        ]) {
             let operator = self.previous().clone();
             let right = self.unary().inspect_err(|_| {
                 self.had_error = true;
@@ -218,9 +414,9 @@ impl Parser {
         Ok(expr)
     }
 
-    /// Parses a unary expression (`!`, `-`).
+    /// Parses a unary expression (`!`, `-`, `~`, prefix `++`/`--`).
     ///
-    /// This method checks for unary operators (`!` or `-`) and, if found,
+    /// This method checks for unary operators (`!`, `-`, `~`, `++` or `--`) and, if found,
     /// recursively parses the operand. Otherwise, it delegates to `primary`.
     ///
     /// # Returns
@@ -229,7 +425,12 @@ impl Parser {
     /// expression or the result of `primary`, or a `ParseError` if an
     /// error occurs.
     fn unary(&mut self) -> Result<Expr, ParseError> {
-        if self.match_tokens(&[TokenType::Bang, TokenType::Minus]) {
+        if self.match_tokens(&[TokenType::PlusPlus, TokenType::MinusMinus]) {
+            let operator = self.previous().clone();
+            let target = self.unary()?;
+            return update(target, operator, true);
+        }
+        if self.match_tokens(&[TokenType::Bang, TokenType::Minus, TokenType::BitwiseNot]) {
             let prefix = self.previous().clone();
             let operator = self.unary().inspect_err(|_| {
                 self.had_error = true;
@@ -240,7 +441,88 @@ impl Parser {
                 operator: Box::new(operator),
             }));
         }
-        self.call()
+        self.exponent()
+    }
+
+    /// Parses an exponentiation expression (`**`).
+    ///
+    /// Exponentiation binds tighter than unary operators and is
+    /// right-associative, so `-2 ** 2` is `-(2 ** 2)` and `2 ** 3 ** 2`
+    /// is `2 ** (3 ** 2)`. The exponent itself may carry a unary prefix.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Expr` representing the parsed exponentiation
+    /// or the result of `postfix`, or a `ParseError` if an error occurs.
+    fn exponent(&mut self) -> Result<Expr, ParseError> {
+        let expr = self.postfix()?;
+        if self.match_tokens(&[TokenType::StarStar]) {
+            let operator = self.previous().clone();
+            let right = self.unary().inspect_err(|_| {
+                self.had_error = true;
+                self.synchronize();
+            })?;
+            return Ok(Expr::Binary(BinaryExpr {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            }));
+        }
+        Ok(expr)
+    }
+
+    /// Parses a postfix increment or decrement (`i++`, `i--`).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Expr` representing the parsed update
+    /// or the result of `call`, or a `ParseError` if an error occurs.
+    fn postfix(&mut self) -> Result<Expr, ParseError> {
+        let expr = self.call()?;
+        if self.match_tokens(&[TokenType::PlusPlus, TokenType::MinusMinus]) {
+            let operator = self.previous().clone();
+            return update(expr, operator, false);
+        }
+        Ok(expr)
+    }
+
+    /// Parses an interpolated string, after its first segment.
+    ///
+    /// The scanner splits `"a ${x} b ${y} c"` into `Interpolation` tokens for the segments
+    /// `a ` and ` b `, each followed by the tokens of the embedded expression and an
+    /// `InterpolationEnd` token, and a plain string token for the last segment ` c`.
+    fn interpolation(&mut self) -> Result<Expr, ParseError> {
+        let token = self.previous().clone();
+        let mut parts = Vec::new();
+
+        loop {
+            let segment = self.previous();
+            parts.push(Expr::Literal(LiteralExpr {
+                literal: Token::new(
+                    TokenType::String,
+                    segment.lexeme(),
+                    segment.literal().clone(),
+                    segment.line(),
+                    segment.token_number(),
+                ),
+            }));
+            parts.push(self.expression()?);
+            self.consume(
+                TokenType::InterpolationEnd,
+                "Expect '}' after interpolated expression.",
+            )?;
+
+            if !self.match_token(TokenType::Interpolation) {
+                break;
+            }
+        }
+
+        let end = self
+            .consume(TokenType::String, "Expect end of interpolated string.")?
+            .clone();
+        parts.push(Expr::Literal(LiteralExpr { literal: end }));
+
+        Ok(Expr::Interpolation(InterpolationExpr { token, parts }))
     }
 
     /// Parses the highest precedence expressions: literals, grouping, and variables.
@@ -270,6 +552,9 @@ impl Parser {
                 literal: self.previous().clone(),
             }));
         }
+        if self.match_token(TokenType::Interpolation) {
+            return self.interpolation();
+        }
         if let TokenType::Var = &self.peek().token_type() {
             self.advance();
             return Ok(Expr::Variable(VariableExpr {
@@ -292,6 +577,16 @@ impl Parser {
 
             return Ok(Expr::Super(SuperExpr { keyword, method }));
         }
+        if self.match_tokens(&[TokenType::Fn, TokenType::BitwiseOr, TokenType::Or]) {
+            return self.lambda();
+        }
+        if self.match_token(TokenType::LeftBracket) {
+            return self.list();
+        }
+        // a brace in statement position is always a block, so here it can only start a map
+        if self.match_token(TokenType::LeftBrace) {
+            return self.map();
+        }
         if self.match_token(TokenType::LeftParen) {
             let _paren_open = self.previous().clone();
             let expr = self.expression().inspect_err(|_| {
@@ -320,6 +615,51 @@ impl Parser {
         Err(error)
     }
 
+    /// Parses a list literal.
+    ///
+    /// This method is called after the opening bracket `[` has been matched. It parses
+    /// comma-separated element expressions (allowing a trailing comma) up to the closing `]`.
+    fn list(&mut self) -> Result<Expr, ParseError> {
+        let bracket = self.previous().clone();
+        let mut elements: Vec<Expr> = Vec::new();
+
+        while !self.check(&TokenType::RightBracket) && !self.is_at_end() {
+            elements.push(self.expression()?);
+            if !self.match_token(TokenType::Comma) {
+                break;
+            }
+        }
+
+        self.consume(TokenType::RightBracket, "Expect ']' after list elements.")?;
+
+        Ok(Expr::List(ListExpr { bracket, elements }))
+    }
+
+    /// Parses a map literal.
+    ///
+    /// This method is called after the opening brace `{` has been matched in expression
+    /// position. It parses comma-separated `key: value` entries (allowing a trailing comma)
+    /// up to the closing `}`. Keys are arbitrary expressions.
+    fn map(&mut self) -> Result<Expr, ParseError> {
+        let brace = self.previous().clone();
+        let mut entries: Vec<(Expr, Expr)> = Vec::new();
+
+        while !self.check(&TokenType::RightBrace) && !self.is_at_end() {
+            let key = self.expression()?;
+            self.consume(TokenType::Colon, "Expect ':' after map key.")?;
+            let value = self.expression()?;
+            entries.push((key, value));
+
+            if !self.match_token(TokenType::Comma) {
+                break;
+            }
+        }
+
+        self.consume(TokenType::RightBrace, "Expect '}' after map entries.")?;
+
+        Ok(Expr::Map(MapExpr { brace, entries }))
+    }
+
     /// Checks if the current token's type is one of the provided types and consumes it if it is.
     ///
     /// This method iterates through the given slice of `TokenType`s. If the
@@ -420,6 +760,13 @@ impl Parser {
         &self.tokens[self.current]
     }
 
+    /// Returns a reference to the token after the current one without consuming anything.
+    ///
+    /// At the end of the input this returns the final `Eof` token.
+    fn peek_next(&self) -> &Token {
+        &self.tokens[(self.current + 1).min(self.tokens.len() - 1)]
+    }
+
     /// Returns a reference to the previously consumed token.
     ///
     /// This is the token that was at the `current` position before the last
@@ -453,9 +800,14 @@ impl Parser {
                 TokenType::Class
                 | TokenType::Fn
                 | TokenType::VarKeyword
+                | TokenType::Const
                 | TokenType::If
                 | TokenType::While
                 | TokenType::Print
+                | TokenType::Import
+                | TokenType::Try
+                | TokenType::Throw
+                | TokenType::Match
                 | TokenType::Return => return,
                 _ => {
                     // Otherwise, consume the current token and continue searching.
@@ -535,6 +887,9 @@ impl Parser {
             TokenType::Return => self.return_statement(),
             TokenType::Break => self.break_statement(),
             TokenType::Continue => self.continue_statement(),
+            TokenType::Try => self.try_statement(),
+            TokenType::Throw => self.throw_statement(),
+            TokenType::Match => self.match_statement(),
             _ => self.expression_statement(),
         }
     }
@@ -551,6 +906,227 @@ impl Parser {
         Ok(Stmt::Continue)
     }
 
+    /// Parses `try { ... } catch (e) { ... } finally { ... }`, where either the catch or
+    /// the finally clause may be left out, but not both.
+    fn try_statement(&mut self) -> Result<Stmt, ParseError> {
+        self.advance();
+        let body = self.block("try")?;
+
+        let mut catch = None;
+        if self.match_token(TokenType::Catch) {
+            self.consume(TokenType::LeftParen, "Expect '(' after 'catch'.")?;
+            let name = self
+                .consume(TokenType::Var, "Expect error variable name.")?
+                .clone();
+            self.consume(TokenType::RightParen, "Expect ')' after error variable.")?;
+            let body = self.block("catch")?;
+            catch = Some(CatchClause { name, body });
+        }
+
+        let finally = if self.match_token(TokenType::Finally) {
+            Some(self.block("finally")?)
+        } else {
+            None
+        };
+
+        if catch.is_none() && finally.is_none() {
+            return Err(error(
+                self.peek(),
+                "Expect 'catch' or 'finally' after try block.".to_string(),
+            ));
+        }
+
+        Ok(Stmt::Try(TryStmt {
+            body,
+            catch,
+            finally,
+        }))
+    }
+
+    fn throw_statement(&mut self) -> Result<Stmt, ParseError> {
+        let keyword = self.advance().clone();
+        let value = self.expression()?;
+        self.consume(TokenType::Semicolon, "Expect ';' after thrown value.")?;
+        Ok(Stmt::Throw(ThrowStmt { keyword, value }))
+    }
+
+    /// Parses `match (value) { pattern if guard => body ... }`.
+    ///
+    /// The guard is optional, see `match_arm_body` for the forms an arm body can take.
+    fn match_statement(&mut self) -> Result<Stmt, ParseError> {
+        let keyword = self.advance().clone();
+        self.consume(TokenType::LeftParen, "Expect '(' after 'match'.")?;
+        let value = self.expression()?;
+        self.consume(TokenType::RightParen, "Expect ')' after match value.")?;
+        self.consume(TokenType::LeftBrace, "Expect '{' before match arms.")?;
+
+        let mut arms = Vec::new();
+        while !self.check(&TokenType::RightBrace) && !self.is_at_end() {
+            let pattern = self.pattern()?;
+            let guard = if self.match_token(TokenType::If) {
+                Some(self.expression()?)
+            } else {
+                None
+            };
+            let arrow = self
+                .consume(TokenType::FatArrow, "Expect '=>' after match pattern.")?
+                .clone();
+            let body = Box::new(self.match_arm_body()?);
+
+            arms.push(MatchArm {
+                pattern,
+                guard,
+                arrow,
+                body,
+            });
+        }
+        self.consume(TokenType::RightBrace, "Expect '}' after match arms.")?;
+
+        Ok(Stmt::Match(MatchStmt {
+            keyword,
+            value,
+            arms,
+        }))
+    }
+
+    /// Parses the body of a match arm.
+    ///
+    /// A body is a block, a `print` or an expression ended by `,` (or `;`, or nothing
+    /// before the closing `}`), or any other statement like `return x;`.
+    fn match_arm_body(&mut self) -> Result<Stmt, ParseError> {
+        let body = match self.peek().token_type() {
+            TokenType::LeftBrace => {
+                let block = self.block_statement()?;
+                self.match_token(TokenType::Comma);
+                return Ok(block);
+            }
+            TokenType::Print => {
+                self.advance();
+                Stmt::Print(self.expression()?)
+            }
+            TokenType::If
+            | TokenType::While
+            | TokenType::For
+            | TokenType::Return
+            | TokenType::Break
+            | TokenType::Continue
+            | TokenType::Try
+            | TokenType::Throw
+            | TokenType::Match => {
+                let statement = self.statement()?;
+                self.match_token(TokenType::Comma);
+                return Ok(statement);
+            }
+            _ => Stmt::Expression(self.expression()?),
+        };
+
+        if !self.match_tokens(&[TokenType::Comma, TokenType::Semicolon])
+            && !self.check(&TokenType::RightBrace)
+        {
+            return Err(error(
+                self.peek(),
+                "Expect ',' after match arm.".to_string(),
+            ));
+        }
+        Ok(body)
+    }
+
+    /// Parses a pattern, including alternatives separated by `|`.
+    fn pattern(&mut self) -> Result<Pattern, ParseError> {
+        let first = self.single_pattern()?;
+        if !self.check(&TokenType::BitwiseOr) {
+            return Ok(first);
+        }
+
+        let mut patterns = vec![first];
+        while self.match_token(TokenType::BitwiseOr) {
+            patterns.push(self.single_pattern()?);
+        }
+        if patterns.iter().any(Pattern::binds_variables) {
+            return Err(error(
+                self.previous(),
+                "Alternative patterns can't bind variables.".to_string(),
+            ));
+        }
+        Ok(Pattern::Alternatives(patterns))
+    }
+
+    /// Parses a literal, wildcard, binding or class pattern.
+    fn single_pattern(&mut self) -> Result<Pattern, ParseError> {
+        if self.match_tokens(&[
+            TokenType::False,
+            TokenType::True,
+            TokenType::Nil,
+            TokenType::Number,
+            TokenType::String,
+        ]) {
+            return Ok(Pattern::Literal(Box::new(Expr::Literal(LiteralExpr {
+                literal: self.previous().clone(),
+            }))));
+        }
+        if self.match_token(TokenType::Minus) {
+            let prefix = self.previous().clone();
+            let number = self
+                .consume(TokenType::Number, "Expect number after '-' in pattern.")?
+                .clone();
+            return Ok(Pattern::Literal(Box::new(Expr::Unary(UnaryExpr {
+                prefix,
+                operator: Box::new(Expr::Literal(LiteralExpr { literal: number })),
+            }))));
+        }
+
+        let name = self.consume(TokenType::Var, "Expect pattern.")?.clone();
+        if name.lexeme() == "_" {
+            return Ok(Pattern::Wildcard);
+        }
+        if !self.match_token(TokenType::LeftParen) {
+            return Ok(Pattern::Binding(name));
+        }
+
+        let mut fields = Vec::new();
+        if !self.check(&TokenType::RightParen) {
+            loop {
+                let field = self.consume(TokenType::Var, "Expect field name.")?.clone();
+                let pattern = if self.match_token(TokenType::Colon) {
+                    self.pattern()?
+                } else {
+                    Pattern::Binding(field.clone())
+                };
+                fields.push((field, pattern));
+
+                if !self.match_token(TokenType::Comma) {
+                    break;
+                }
+            }
+        }
+        self.consume(
+            TokenType::RightParen,
+            "Expect ')' after class pattern fields.",
+        )?;
+
+        Ok(Pattern::Class(ClassPattern {
+            class: VariableExpr { name },
+            fields,
+        }))
+    }
+
+    /// Parses a `{ ... }` block that belongs to a `kind` clause and returns its statements.
+    fn block(&mut self, kind: &str) -> Result<Vec<Stmt>, ParseError> {
+        if !self.check(&TokenType::LeftBrace) {
+            return Err(error(
+                self.peek(),
+                format!("Expect '{{' before {kind} block."),
+            ));
+        }
+        match self.block_statement()? {
+            Stmt::Block(stmts) => Ok(stmts),
+            _ => Err(error(
+                self.previous(),
+                format!("Expected block statement as {kind} block"),
+            )),
+        }
+    }
+
     fn return_statement(&mut self) -> Result<Stmt, ParseError> {
         self.advance();
 
@@ -584,8 +1160,14 @@ impl Parser {
     fn declaration(&mut self) -> Result<Stmt, ParseError> {
         match self.peek().token_type() {
             TokenType::VarKeyword => self.variable_declaration(),
+            TokenType::Const => self.const_declaration(),
             TokenType::Class => self.class_declaration(),
-            TokenType::Fn => self.function("function"),
+            TokenType::Trait => self.trait_declaration(),
+            TokenType::Import => self.import_declaration(),
+            // `fn (` starts an anonymous function, which is an expression statement
+            TokenType::Fn if self.peek_next().token_type() == &TokenType::Var => {
+                self.function("function")
+            }
             _ => self.statement(),
         }
         .inspect_err(|_| {
@@ -593,6 +1175,58 @@ impl Parser {
         })
     }
 
+    /// Parses an import statement: `import "path.bl";` or `import "path.bl" as name;`.
+    ///
+    /// Without an `as` clause the module is bound to the file name without its extension,
+    /// which therefore has to be a valid identifier.
+    fn import_declaration(&mut self) -> Result<Stmt, ParseError> {
+        let keyword = self.advance().clone();
+        let path = self
+            .consume(TokenType::String, "Expect module path after 'import'.")?
+            .clone();
+
+        let name = if self.match_token(TokenType::As) {
+            self.consume(TokenType::Var, "Expect module name after 'as'.")?
+                .clone()
+        } else {
+            let stem = match path.literal() {
+                Some(Object::String(p)) => std::path::Path::new(p)
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned())
+                    .unwrap_or_default(),
+                _ => String::new(),
+            };
+            let valid = !stem.is_empty()
+                && stem
+                    .chars()
+                    .enumerate()
+                    .all(|(i, c)| is_valid_variable_char(c, i == 0));
+            if !valid || is_keyword(&stem) {
+                return Err(error(
+                    &path,
+                    format!(
+                        "Module name '{}' is not a valid identifier, use 'as' to name it.",
+                        stem
+                    ),
+                ));
+            }
+            Token::new(
+                TokenType::Var,
+                &stem,
+                None,
+                path.line(),
+                path.token_number(),
+            )
+        };
+
+        self.consume(TokenType::Semicolon, "Expect ';' after import.")?;
+        Ok(Stmt::Import(ImportStmt {
+            keyword,
+            path,
+            name,
+        }))
+    }
+
     fn class_declaration(&mut self) -> Result<Stmt, ParseError> {
         self.advance();
         let name = self.consume(TokenType::Var, "Expect class name.")?.clone();
@@ -608,14 +1242,43 @@ impl Parser {
             });
         }
 
+        let mut traits = Vec::new();
+        if self.match_token(TokenType::With) {
+            loop {
+                let name = self.consume(TokenType::Var, "Expect trait name.")?.clone();
+                traits.push(VariableExpr { name });
+                if !self.match_token(TokenType::Comma) {
+                    break;
+                }
+            }
+        }
+
         self.consume(TokenType::LeftBrace, "Expect '{' before class body.")?;
 
         let mut methods: Vec<FunctionStmt> = Vec::new();
+        let mut static_fields: Vec<VarStmt> = Vec::new();
         while !self.check(&TokenType::RightBrace) && !self.is_at_end() {
             // Check for static keyword before fn
             let is_static = self.match_token(TokenType::Static);
+            if is_static && self.check(&TokenType::VarKeyword) {
+                if let Stmt::Var(field) = self.variable_declaration()? {
+                    static_fields.push(field);
+                }
+                continue;
+            }
+            // `get` and `set` are only special in front of a method name
+            let accessor = match self.peek().lexeme() {
+                "get" | "set" if *self.peek_next().token_type() == TokenType::Var => {
+                    Some(self.peek().clone())
+                }
+                _ => None,
+            };
+
             if let Stmt::Function(mut method) = self.function("method")? {
                 method.is_static = is_static;
+                if let Some(accessor) = accessor {
+                    self.accessor(&mut method, &accessor)?;
+                }
                 methods.push(method);
             } else {
                 return Err(error(
@@ -633,42 +1296,118 @@ impl Parser {
         Ok(Stmt::Class(ClassStmt {
             name,
             superclass,
+            traits,
             methods,
+            static_fields,
         }))
     }
 
+    /// Parses a trait declaration: `trait Name { fn required(); fn provided() { ... } }`.
+    fn trait_declaration(&mut self) -> Result<Stmt, ParseError> {
+        self.advance();
+        let name = self.consume(TokenType::Var, "Expect trait name.")?.clone();
+        self.consume(TokenType::LeftBrace, "Expect '{' before trait body.")?;
+
+        let mut methods = Vec::new();
+        let mut required = Vec::new();
+        while !self.check(&TokenType::RightBrace) && !self.is_at_end() {
+            self.consume(TokenType::Fn, "Expect 'fn' before trait method.")?;
+            let method_name = self.consume(TokenType::Var, "Expect method name.")?.clone();
+            let params = self.parameters("method")?;
+
+            // a method without a body has to be provided by the class
+            if self.match_token(TokenType::Semicolon) {
+                required.push(method_name);
+            } else {
+                methods.push(self.function_block(method_name, params, "method")?);
+            }
+        }
+
+        self.consume(TokenType::RightBrace, "Expect '}' after trait body.")?;
+
+        Ok(Stmt::Trait(TraitStmt {
+            name,
+            methods,
+            required,
+        }))
+    }
+
+    /// Turns a method parsed after `get` or `set` into a getter or setter, checking that
+    /// it takes the right parameters.
+    fn accessor(&self, method: &mut FunctionStmt, accessor: &Token) -> Result<(), ParseError> {
+        let message = if method.is_static {
+            Some("Getters and setters can't be static.")
+        } else if accessor.lexeme() == "get" {
+            method.is_getter = true;
+            (!method.params.is_empty()).then_some("Getters can't have parameters.")
+        } else {
+            method.is_setter = true;
+            match method.params.as_slice() {
+                [param] if param.default.is_none() && !param.variadic => None,
+                _ => Some("Setters must take exactly one parameter."),
+            }
+        };
+
+        match message {
+            Some(message) => Err(error(accessor, message.to_string())),
+            None => Ok(()),
+        }
+    }
+
     fn function(&mut self, kind: &str) -> Result<Stmt, ParseError> {
         self.advance();
-        let name = self
-            .consume(TokenType::Var, &format!("Expect {kind} name."))?
-            .clone();
+        // only methods can be private
+        let name = if kind == "method" && self.check(&TokenType::PrivateName) {
+            self.advance().clone()
+        } else {
+            self.consume(TokenType::Var, &format!("Expect {kind} name."))?
+                .clone()
+        };
 
+        Ok(Stmt::Function(self.function_body(name, kind)?))
+    }
+
+    /// Parses the parameter list and block body of a function.
+    ///
+    /// This is shared by named function declarations, methods and anonymous
+    /// `fn (...) { ... }` expressions; `name` is the already consumed (or, for
+    /// anonymous functions, synthesized) name of the function.
+    fn function_body(&mut self, name: Token, kind: &str) -> Result<FunctionStmt, ParseError> {
+        let params = self.parameters(kind)?;
+        self.function_block(name, params, kind)
+    }
+
+    /// Parses the parenthesized parameter list of a function.
+    fn parameters(&mut self, kind: &str) -> Result<Vec<Param>, ParseError> {
         self.consume(
             TokenType::LeftParen,
             &format!("Expect '(' after {kind} name."),
         )?;
 
-        let mut params: Vec<Token> = Vec::new();
+        let mut params: Vec<Param> = Vec::new();
 
         if !self.check(&TokenType::RightParen) {
-            params.push(
-                self.consume(TokenType::Var, "Expect parameter name.")?
-                    .clone(),
-            );
+            params.push(self.parameter(&params, true)?);
             while self.match_token(TokenType::Comma) {
                 if params.len() >= 255 {
                     error(self.peek(), "Can't have more than 255 arguments.".into());
                 }
 
-                params.push(
-                    self.consume(TokenType::Var, "Expect parameter name.")?
-                        .clone(),
-                );
+                params.push(self.parameter(&params, true)?);
             }
         }
 
         self.consume(TokenType::RightParen, "Expect ')' after parameters.")?;
+        Ok(params)
+    }
 
+    /// Parses the block body of a function whose name and parameters were already parsed.
+    fn function_block(
+        &mut self,
+        name: Token,
+        params: Vec<Param>,
+        kind: &str,
+    ) -> Result<FunctionStmt, ParseError> {
         // we cant consume the token here because block_statement() consumes the opening brace in
         // the bginning
         if !self.check(&TokenType::LeftBrace) {
@@ -690,11 +1429,119 @@ impl Parser {
             }
         };
 
-        Ok(Stmt::Function(FunctionStmt {
+        Ok(FunctionStmt {
             name,
             params,
             body: body_stmts,
             is_static: false, // Default to false, will be updated by class_declaration if needed
+            is_getter: false,
+            is_setter: false,
+        })
+    }
+
+    /// Parses a single parameter: `name`, `name = default` or `...name`.
+    ///
+    /// `params` are the parameters parsed so far. Default values are only allowed when
+    /// `allow_default` is set, since in `|a = 1| ...` lambdas the closing `|` would be
+    /// read as part of the default value.
+    fn parameter(&mut self, params: &[Param], allow_default: bool) -> Result<Param, ParseError> {
+        if params.last().is_some_and(|param| param.variadic) {
+            return Err(error(
+                self.peek(),
+                "Variadic parameter must be the last parameter.".to_string(),
+            ));
+        }
+
+        let variadic = self.match_token(TokenType::Ellipsis);
+        let name = self
+            .consume(TokenType::Var, "Expect parameter name.")?
+            .clone();
+
+        let default = if self.check(&TokenType::Equal) {
+            if variadic || !allow_default {
+                return Err(error(
+                    self.peek(),
+                    "This parameter can't have a default value.".to_string(),
+                ));
+            }
+            self.advance();
+            Some(self.expression()?)
+        } else {
+            if !variadic && params.iter().any(|param| param.default.is_some()) {
+                return Err(error(
+                    &name,
+                    "Parameter without default value can't follow one with a default.".to_string(),
+                ));
+            }
+            None
+        };
+
+        Ok(Param {
+            name,
+            default,
+            variadic,
+        })
+    }
+
+    /// Parses an anonymous function expression.
+    ///
+    /// This method is called after the introducing token has been matched, which is
+    /// either `fn` for the `fn (a, b) { ... }` form, or `|`/`||` for the short
+    /// `|a, b| expr` form. A short lambda whose body is not a block returns the value
+    /// of its body expression.
+    fn lambda(&mut self) -> Result<Expr, ParseError> {
+        let keyword = self.previous().clone();
+        let name = Token::new(
+            TokenType::Var,
+            "lambda",
+            None,
+            keyword.line(),
+            keyword.token_number(),
+        );
+
+        if *keyword.token_type() == TokenType::Fn {
+            let declaration = self.function_body(name, "lambda")?;
+            return Ok(Expr::Lambda(LambdaExpr { declaration }));
+        }
+
+        let mut params: Vec<Param> = Vec::new();
+        if *keyword.token_type() == TokenType::BitwiseOr {
+            while !self.check(&TokenType::BitwiseOr) && !self.is_at_end() {
+                params.push(self.parameter(&params, false)?);
+                if !self.match_token(TokenType::Comma) {
+                    break;
+                }
+            }
+            self.consume(TokenType::BitwiseOr, "Expect '|' after lambda parameters.")?;
+        }
+
+        let body = if self.check(&TokenType::LeftBrace) {
+            match self.block_statement()? {
+                Stmt::Block(stmts) => stmts,
+                _ => {
+                    return Err(error(
+                        &keyword,
+                        "Expected block statement as lambda body".to_string(),
+                    ))
+                }
+            }
+        } else {
+            let value = self.expression()?;
+            vec![Stmt::Return(ReturnStmt {
+                keyword: keyword.clone(),
+                value: Some(Box::new(value)),
+            })]
+        };
+
+        Ok(Expr::Lambda(LambdaExpr {
+            declaration: FunctionStmt {
+                name,
+                params,
+                body,
+                is_static: false,
+                is_getter: false,
+                is_setter: false,
+            },
         }))
     }
 
@@ -706,19 +1553,25 @@ impl Parser {
     /// The initializer, condition, and increment are all optional.
     ///
     /// This function consumes the 'for' keyword, parses the parenthesized clauses,
-    /// and then parses the loop body. It then transforms the `for` loop
-    /// into a `while` loop with an optional initializer and increment.
+    /// and then parses the loop body into a `Stmt::For`. The loop is not desugared
+    /// into a `while` loop, the interpreter runs it with `run_for_loop`, which gives
+    /// every iteration its own environment. A `for (name in iterable)` loop is
+    /// parsed by `for_in_statement`.
     fn for_statement(&mut self) -> Result<Stmt, ParseError> {
-        self.advance(); // Consume 'for'
+        let keyword = self.advance().clone(); // Consume 'for'
 
         self.consume(TokenType::LeftParen, "Expect '(' after 'for'.")?;
 
+        if self.check(&TokenType::Var) && self.peek_next().token_type() == &TokenType::In {
+            return self.for_in_statement(keyword);
+        }
+
         let initializer = if self.match_token(TokenType::Semicolon) {
             None
         } else if self.check(&TokenType::VarKeyword) {
-            Some(self.variable_declaration()?)
+            Some(Box::new(self.variable_declaration()?))
         } else {
-            Some(self.expression_statement()?)
+            Some(Box::new(self.expression_statement()?))
         };
 
         let condition = if !self.check(&TokenType::Semicolon) {
@@ -735,30 +1588,32 @@ impl Parser {
         };
         self.consume(TokenType::RightParen, "Expect ')' after for clauses.")?;
 
-        let mut body = self.statement()?;
+        let body = Box::new(self.statement()?);
 
-        if let Some(inc) = increment {
-            body = Stmt::Block(vec![body, Stmt::Expression(inc)]);
-        }
+        Ok(Stmt::For(ForStmt {
+            initializer,
+            condition,
+            increment,
+            body,
+        }))
+    }
 
-        body = Stmt::While(WhileStmt {
-            condition: condition.unwrap_or(Expr::Literal(LiteralExpr {
-                literal: Token::new(
-                    TokenType::True,
-                    "true",
-                    Some(Object::Boolean(true)),
-                    self.peek().line(),
-                    self.peek().token_number(),
-                ),
-            })),
-            body: Box::new(body),
-        });
+    /// Parses the rest of a `for (name in iterable) body` loop, after the opening parenthesis.
+    fn for_in_statement(&mut self, keyword: Token) -> Result<Stmt, ParseError> {
+        let name = self.advance().clone();
+        self.advance(); // Consume 'in'
 
-        if let Some(init) = initializer {
-            body = Stmt::Block(vec![init, body]);
-        }
+        let iterable = self.expression()?;
+        self.consume(TokenType::RightParen, "Expect ')' after for-in clause.")?;
 
-        Ok(body)
+        let body = Box::new(self.statement()?);
+
+        Ok(Stmt::ForIn(ForInStmt {
+            keyword,
+            name,
+            iterable,
+            body,
+        }))
     }
 
     /// Parses a `while` statement.
@@ -802,15 +1657,36 @@ impl Parser {
         };
 
         if !self.match_token(TokenType::Semicolon) {
-            return Err(error(
+            Err(error(
                 self.peek(),
                 "Expect ';' after expression.".to_string(),
-            ));
+            ))
         } else {
-            Ok(Stmt::Var(VarStmt { name, initializer }))
+            Ok(Stmt::Var(VarStmt {
+                name,
+                initializer,
+                is_const: false,
+            }))
         }
     }
 
+    /// Parses a constant declaration: `const NAME = value;`.
+    fn const_declaration(&mut self) -> Result<Stmt, ParseError> {
+        self.advance();
+        let name = self
+            .consume(TokenType::Var, "Expect constant name.")?
+            .clone();
+        self.consume(TokenType::Equal, "Constants must be initialized.")?;
+        let initializer = self.expression()?;
+        self.consume(TokenType::Semicolon, "Expect ';' after expression.")?;
+
+        Ok(Stmt::Var(VarStmt {
+            name,
+            initializer: Some(initializer),
+            is_const: true,
+        }))
+    }
+
     /// Returns a 'Stmt::Print' statement after evaluating the expression signaling to the
     /// StmtVisitor to print the result
     fn print_statement(&mut self) -> Result<Stmt, ParseError> {
@@ -852,23 +1728,39 @@ impl Parser {
     /// Parses a function call expression.
     ///
     /// This function handles the parsing of a primary expression followed by zero or more
-    /// function calls. It iteratively checks for left parentheses `(` to identify call
-    /// expressions and delegates the parsing of the arguments to the `finish_call`
-    /// function.
+    /// function calls, property accesses and index accesses. It iteratively checks for left
+    /// parentheses `(` to identify call expressions and delegates the parsing of the arguments
+    /// to the `finish_call` function.
     fn call(&mut self) -> Result<Expr, ParseError> {
         let mut expr = self.primary()?;
 
         loop {
             if self.match_token(TokenType::LeftParen) {
                 expr = self.finish_call(expr)?;
-            } else if self.match_token(TokenType::Dot) {
-                let name = self
-                    .consume(TokenType::Var, "Expect property name after '.'.")?
-                    .clone();
+            } else if self.match_tokens(&[TokenType::Dot, TokenType::QuestionDot]) {
+                let optional = *self.previous().token_type() == TokenType::QuestionDot;
+                let name = if self.check(&TokenType::PrivateName) {
+                    self.advance().clone()
+                } else {
+                    self.consume(TokenType::Var, "Expect property name after '.'.")?
+                        .clone()
+                };
 
                 expr = Expr::PropertyAccess(PropertyAccessExpr {
                     object: Box::new(expr),
                     name,
+                    optional,
+                });
+            } else if self.match_token(TokenType::LeftBracket) {
+                let index = self.expression()?;
+                let bracket = self
+                    .consume(TokenType::RightBracket, "Expect ']' after index.")?
+                    .clone();
+
+                expr = Expr::Index(IndexExpr {
+                    object: Box::new(expr),
+                    bracket,
+                    index: Box::new(index),
                 });
             } else {
                 break;
@@ -892,18 +1784,34 @@ impl Parser {
     ///
     fn finish_call(&mut self, callee: Expr) -> Result<Expr, ParseError> {
         let mut arguments: Vec<Expr> = Vec::new();
+        let mut named_arguments: Vec<(Token, Expr)> = Vec::new();
 
         if !self.check(&TokenType::RightParen) {
-            arguments.push(self.expression()?);
-            while self.match_token(TokenType::Comma) {
+            loop {
                 // technically we could make this as long as possible but most language specs dont
                 // and its gonna make it easier to implement a bytcode VM or make a compiler out
                 // of this...
-                if arguments.len() >= 255 {
+                if arguments.len() + named_arguments.len() >= 255 {
                     error(self.peek(), "Can't have more than 255 arguments.".into());
                 }
 
-                arguments.push(self.expression()?);
+                if self.check(&TokenType::Var) && *self.peek_next().token_type() == TokenType::Colon
+                {
+                    let name = self.advance().clone();
+                    self.advance(); // Consume ':'
+                    named_arguments.push((name, self.expression()?));
+                } else if !named_arguments.is_empty() {
+                    return Err(error(
+                        self.peek(),
+                        "Positional argument can't follow named arguments.".to_string(),
+                    ));
+                } else {
+                    arguments.push(self.expression()?);
+                }
+
+                if !self.match_token(TokenType::Comma) {
+                    break;
+                }
             }
         }
 
@@ -913,6 +1821,7 @@ impl Parser {
                 .consume(TokenType::RightParen, "Expect ')' after arguments.")?
                 .clone(),
             arguments,
+            named_arguments,
         }))
     }
 }
@@ -955,6 +1864,43 @@ pub fn parse(token_input: Vec<Token>) -> Result<Vec<Stmt>, Vec<ParseError>> {
     }
 }
 
+/// Returns the binary operator applied by a compound assignment token (`+` for `+=`),
+/// or `None` for a plain `=`.
+fn compound_operator(equals: &Token) -> Option<Token> {
+    let (token_type, lexeme) = match equals.token_type() {
+        TokenType::PlusEqual => (TokenType::Plus, "+"),
+        TokenType::MinusEqual => (TokenType::Minus, "-"),
+        TokenType::StarEqual => (TokenType::Asterisk, "*"),
+        TokenType::SlashEqual => (TokenType::Slash, "/"),
+        TokenType::PercentEqual => (TokenType::Percent, "%"),
+        _ => return None,
+    };
+    Some(Token::new(
+        token_type,
+        lexeme,
+        None,
+        equals.line(),
+        equals.token_number(),
+    ))
+}
+
+/// Builds an increment or decrement of `target`, which has to be assignable.
+fn update(target: Expr, operator: Token, prefix: bool) -> Result<Expr, ParseError> {
+    match target {
+        Expr::PropertyAccess(ref property) if property.optional => {
+            Err(error(&operator, "Invalid assignment target.".to_string()))
+        }
+        Expr::Variable(_) | Expr::PropertyAccess(_) | Expr::Index(_) => {
+            Ok(Expr::Update(UpdateExpr {
+                target: Box::new(target),
+                operator,
+                prefix,
+            }))
+        }
+        _ => Err(error(&operator, "Invalid assignment target.".to_string())),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::expr_types::*;
@@ -1527,96 +2473,64 @@ mod tests {
         let statements = result.unwrap();
         assert_eq!(statements.len(), 1);
 
-        // For loops are desugared into while loops with blocks
         match &statements[0] {
-            Stmt::Block(block_stmts) => {
-                assert_eq!(block_stmts.len(), 2);
-
-                // First statement should be variable declaration for initializer
-                match &block_stmts[0] {
-                    Stmt::Var(var_stmt) => {
+            Stmt::For(for_stmt) => {
+                // Initializer should be the variable declaration
+                match for_stmt.initializer.as_deref() {
+                    Some(Stmt::Var(var_stmt)) => {
                         assert_eq!(var_stmt.name.lexeme(), "i");
                     }
                     _ => panic!("Expected variable declaration statement for initializer"),
                 }
 
-                // Second statement should be while loop
-                match &block_stmts[1] {
-                    Stmt::While(while_stmt) => {
-                        // Check condition
-                        match &while_stmt.condition {
-                            Expr::Binary(bin_expr) => {
-                                match &*bin_expr.left {
-                                    Expr::Variable(var_expr) => {
-                                        assert_eq!(var_expr.name.lexeme(), "i");
-                                    }
-                                    _ => panic!("Expected variable expression as left operand"),
-                                }
+                // Check condition
+                match &for_stmt.condition {
+                    Some(Expr::Binary(bin_expr)) => {
+                        match &*bin_expr.left {
+                            Expr::Variable(var_expr) => {
+                                assert_eq!(var_expr.name.lexeme(), "i");
+                            }
+                            _ => panic!("Expected variable expression as left operand"),
+                        }
 
-                                assert_eq!(bin_expr.operator.token_type(), &TokenType::Less);
+                        assert_eq!(bin_expr.operator.token_type(), &TokenType::Less);
 
-                                match &*bin_expr.right {
-                                    Expr::Literal(lit_expr) => {
-                                        if let Some(Object::Number(value)) =
-                                            lit_expr.literal.literal()
-                                        {
-                                            assert_eq!(*value, 10.0);
-                                        } else {
-                                            panic!("Expected number literal");
-                                        }
-                                    }
-                                    _ => panic!("Expected literal expression as right operand"),
+                        match &*bin_expr.right {
+                            Expr::Literal(lit_expr) => {
+                                if let Some(Object::Number(value)) = lit_expr.literal.literal() {
+                                    assert_eq!(*value, 10.0);
+                                } else {
+                                    panic!("Expected number literal");
                                 }
                             }
-                            _ => panic!("Expected binary expression as condition"),
+                            _ => panic!("Expected literal expression as right operand"),
                         }
+                    }
+                    _ => panic!("Expected binary expression as condition"),
+                }
 
-                        // Check body (should be a block with the original body and increment)
-                        match &*while_stmt.body {
-                            Stmt::Block(body_stmts) => {
-                                assert_eq!(body_stmts.len(), 2);
-
-                                // First statement should be the original body
-                                match &body_stmts[0] {
-                                    Stmt::Block(original_body) => {
-                                        assert_eq!(original_body.len(), 1);
-
-                                        match &original_body[0] {
-                                            Stmt::Print(expr) => match expr {
-                                                Expr::Variable(var_expr) => {
-                                                    assert_eq!(var_expr.name.lexeme(), "i");
-                                                }
-                                                _ => panic!("Expected variable expression"),
-                                            },
-                                            _ => panic!("Expected print statement"),
-                                        }
-                                    }
-                                    _ => panic!("Expected block statement as original body"),
-                                }
+                // Check increment
+                match &for_stmt.increment {
+                    Some(Expr::Assign(_)) => {}
+                    _ => panic!("Expected assignment expression as increment"),
+                }
 
-                                // Second statement should be the increment
-                                match &body_stmts[1] {
-                                    Stmt::Expression(expr) => {
-                                        match expr {
-                                            Expr::Assign(_) => {
-                                                // This is the increment expression
-                                                // We could check more details but this is sufficient
-                                            }
-                                            _ => panic!(
-                                                "Expected assignment expression as increment"
-                                            ),
-                                        }
-                                    }
-                                    _ => panic!("Expected expression statement as increment"),
-                                }
+                // Check body
+                match &*for_stmt.body {
+                    Stmt::Block(body_stmts) => {
+                        assert_eq!(body_stmts.len(), 1);
+
+                        match &body_stmts[0] {
+                            Stmt::Print(Expr::Variable(var_expr)) => {
+                                assert_eq!(var_expr.name.lexeme(), "i");
                             }
-                            _ => panic!("Expected block statement as while body"),
+                            _ => panic!("Expected print statement"),
                         }
                     }
-                    _ => panic!("Expected while statement as desugared for loop"),
+                    _ => panic!("Expected block statement as loop body"),
                 }
             }
-            _ => panic!("Expected block statement as desugared for loop"),
+            _ => panic!("Expected for statement"),
         }
     }
 
@@ -1653,8 +2567,8 @@ mod tests {
 
                 // Check parameters
                 assert_eq!(func_stmt.params.len(), 2);
-                assert_eq!(func_stmt.params[0].lexeme(), "a");
-                assert_eq!(func_stmt.params[1].lexeme(), "b");
+                assert_eq!(func_stmt.params[0].name.lexeme(), "a");
+                assert_eq!(func_stmt.params[1].name.lexeme(), "b");
 
                 // Check body
                 assert_eq!(func_stmt.body.len(), 1);
@@ -1689,4 +2603,33 @@ mod tests {
             _ => panic!("Expected function declaration"),
         }
     }
+
+    #[test]
+    fn test_parse_lambda_expression() {
+        // |x| x;
+        let tokens = vec![
+            token(TokenType::BitwiseOr, "|", None, 1),
+            token(TokenType::Var, "x", None, 1),
+            token(TokenType::BitwiseOr, "|", None, 1),
+            token(TokenType::Var, "x", None, 1),
+            token(TokenType::Semicolon, ";", None, 1),
+            token(TokenType::Eof, "", None, 1),
+        ];
+
+        let statements = parser::parse(tokens).expect("Parsing failed");
+        assert_eq!(statements.len(), 1);
+
+        match &statements[0] {
+            Stmt::Expression(Expr::Lambda(lambda)) => {
+                assert_eq!(lambda.declaration.name.lexeme(), "lambda");
+                assert_eq!(lambda.declaration.params.len(), 1);
+                assert_eq!(lambda.declaration.params[0].name.lexeme(), "x");
+                assert!(matches!(
+                    &lambda.declaration.body[..],
+                    [Stmt::Return(ReturnStmt { value: Some(_), .. })]
+                ));
+            }
+            _ => panic!("Expected lambda expression statement"),
+        }
+    }
 }
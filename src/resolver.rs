@@ -2,14 +2,15 @@ use std::collections::HashMap;
 use std::mem;
 
 use crate::{
-    expr_types::VariableExpr, expr_types::*, runtime_error::RuntimeError, stmt_types::*,
-    token::Token, Interpreter,
+    class::private_key, expr_types::VariableExpr, expr_types::*, runtime_error::RuntimeError,
+    stmt_types::*, token::Token, token::TokenType, Interpreter,
 };
 
 enum ClassType {
     None,
     Class,
     Subclass,
+    Trait,
 }
 
 enum FunctionType {
@@ -31,19 +32,34 @@ pub struct Resolver<'a> {
     /// The value associated with a key in the scope map represents
     /// whether or not we have finished resolving that variable’s initializer.
     scopes: Vec<HashMap<String, bool>>,
+    /// the `const` declarations of each scope in `scopes`, by name
+    constants: Vec<HashMap<String, Token>>,
+    /// the `const` declarations of the global scope
+    global_constants: HashMap<String, Token>,
     loop_depth: usize,
     current_function: FunctionType,
+    /// problems that don't stop the program from running, like unreachable match arms
+    pub warnings: Vec<String>,
     current_class: ClassType,
+    /// the name of the class whose body is being resolved, used to qualify private members
+    class_name: Option<Token>,
+    /// the names of the methods each declared trait provides, by trait name
+    traits: HashMap<String, Vec<String>>,
 }
 
 impl Resolver<'_> {
-    pub fn new(interpreter: &mut Interpreter) -> Resolver {
+    pub fn new(interpreter: &mut Interpreter) -> Resolver<'_> {
         Resolver {
             interpreter,
             scopes: Vec::new(),
+            constants: Vec::new(),
+            global_constants: HashMap::new(),
             loop_depth: 0,
             current_class: ClassType::None,
+            class_name: None,
             current_function: FunctionType::None,
+            warnings: Vec::new(),
+            traits: HashMap::new(),
         }
     }
 }
@@ -64,6 +80,16 @@ impl StmtVisitor<()> for Resolver<'_> {
         }
 
         self.declare(&stmt.name, true)?;
+
+        let constants = match self.constants.last_mut() {
+            Some(constants) => constants,
+            None => &mut self.global_constants,
+        };
+        if stmt.is_const {
+            constants.insert(stmt.name.lexeme().to_owned(), stmt.name.clone());
+        } else {
+            constants.remove(stmt.name.lexeme());
+        }
         Ok(())
     }
 
@@ -120,6 +146,36 @@ impl StmtVisitor<()> for Resolver<'_> {
         Ok(())
     }
 
+    fn visit_for_stmt(&mut self, stmt: &mut ForStmt) -> Result<(), RuntimeError> {
+        self.begin_scope()?;
+        if let Some(initializer) = &mut stmt.initializer {
+            self.resolve_stmt(initializer)?;
+        }
+        if let Some(condition) = &mut stmt.condition {
+            self.resolve_expr(condition)?;
+        }
+        if let Some(increment) = &mut stmt.increment {
+            self.resolve_expr(increment)?;
+        }
+        self.loop_depth += 1;
+        self.resolve_stmt(&mut stmt.body)?;
+        self.loop_depth -= 1;
+        self.end_scope()?;
+        Ok(())
+    }
+
+    fn visit_for_in_stmt(&mut self, stmt: &mut ForInStmt) -> Result<(), RuntimeError> {
+        self.resolve_expr(&mut stmt.iterable)?;
+
+        self.begin_scope()?;
+        self.declare(&stmt.name, true)?;
+        self.loop_depth += 1;
+        self.resolve_stmt(&mut stmt.body)?;
+        self.loop_depth -= 1;
+        self.end_scope()?;
+        Ok(())
+    }
+
     fn visit_continue_stmt(&mut self) -> Result<(), RuntimeError> {
         if self.loop_depth == 0 {
             Err(RuntimeError::other(
@@ -144,10 +200,17 @@ impl StmtVisitor<()> for Resolver<'_> {
 
     fn visit_class_stmt(&mut self, stmt: &mut ClassStmt) -> Result<(), RuntimeError> {
         let enclosing_class = mem::replace(&mut self.current_class, ClassType::Class);
-        self.current_class = ClassType::Class;
+        let enclosing_name = self.class_name.replace(stmt.name.clone());
 
         self.declare(&stmt.name, false)?;
 
+        // static fields are initialized in the scope the class is declared in
+        for field in &mut stmt.static_fields {
+            if let Some(initializer) = &mut field.initializer {
+                self.resolve_expr(initializer)?;
+            }
+        }
+
         if let Some(superclass) = &stmt.superclass {
             if stmt.name.lexeme() == superclass.name.lexeme() {
                 return Err(RuntimeError::other(
@@ -166,33 +229,33 @@ impl StmtVisitor<()> for Resolver<'_> {
                 .insert("super".to_string(), true);
         }
 
+        self.resolve_traits(stmt)?;
+
         self.begin_scope()?;
-        
-        // Only add 'this' binding for non-static methods
+
+        // in static methods 'this' is the class itself
         self.scopes
             .last_mut()
             .unwrap()
             .insert("this".to_string(), true);
 
         for method in &mut stmt.methods {
+            if *method.name.token_type() == TokenType::PrivateName {
+                method.name = self.private_name(&method.name);
+            }
+
             let mut declaration = FunctionType::Method;
 
-            if method.name.lexeme().eq("init") {
+            if method.name.lexeme().eq("init") && !method.is_static {
                 declaration = FunctionType::Initializer;
             }
-            
-            // Don't allow accessing 'this' in static methods
-            if method.is_static {
-                self.scopes.last_mut().unwrap().remove("this");
-                self.resolve_function(method, declaration)?;
-                self.scopes.last_mut().unwrap().insert("this".to_string(), true);
-            } else {
-                self.resolve_function(method, declaration)?;
-            }
+
+            self.resolve_function(method, declaration)?;
         }
 
         self.end_scope()?;
         self.current_class = enclosing_class;
+        self.class_name = enclosing_name;
 
         if stmt.superclass.is_some() {
             self.end_scope()?;
@@ -200,6 +263,84 @@ impl StmtVisitor<()> for Resolver<'_> {
 
         self.declare(&stmt.name, true)
     }
+
+    fn visit_trait_stmt(&mut self, stmt: &mut TraitStmt) -> Result<(), RuntimeError> {
+        self.declare(&stmt.name, true)?;
+        self.traits.insert(
+            stmt.name.lexeme().to_string(),
+            stmt.methods
+                .iter()
+                .map(|method| method.name.lexeme().to_string())
+                .collect(),
+        );
+
+        let enclosing_class = mem::replace(&mut self.current_class, ClassType::Trait);
+        let enclosing_name = self.class_name.take();
+        self.begin_scope()?;
+        self.scopes
+            .last_mut()
+            .unwrap()
+            .insert("this".to_string(), true);
+
+        for method in &mut stmt.methods {
+            self.resolve_function(method, FunctionType::Method)?;
+        }
+
+        self.end_scope()?;
+        self.current_class = enclosing_class;
+        self.class_name = enclosing_name;
+        Ok(())
+    }
+
+    // the imported module is resolved on its own when it gets loaded at runtime
+    fn visit_import_stmt(&mut self, stmt: &mut ImportStmt) -> Result<(), RuntimeError> {
+        self.declare(&stmt.name, true)
+    }
+
+    fn visit_try_stmt(&mut self, stmt: &mut TryStmt) -> Result<(), RuntimeError> {
+        self.visit_block_stmt(&mut stmt.body)?;
+
+        if let Some(catch) = &mut stmt.catch {
+            self.begin_scope()?;
+            self.declare(&catch.name, true)?;
+            self.resolve_stmts(&mut catch.body)?;
+            self.end_scope()?;
+        }
+
+        if let Some(finally) = &mut stmt.finally {
+            self.visit_block_stmt(finally)?;
+        }
+        Ok(())
+    }
+
+    fn visit_throw_stmt(&mut self, stmt: &mut ThrowStmt) -> Result<(), RuntimeError> {
+        self.resolve_expr(&mut stmt.value)
+    }
+
+    fn visit_match_stmt(&mut self, stmt: &mut MatchStmt) -> Result<(), RuntimeError> {
+        self.resolve_expr(&mut stmt.value)?;
+
+        let mut exhausted = false;
+        for arm in &mut stmt.arms {
+            if exhausted {
+                self.warnings.push(format!(
+                    "[line: {}] Unreachable match arm, an earlier arm matches every value.",
+                    arm.arrow.line()
+                ));
+            }
+
+            self.begin_scope()?;
+            self.resolve_pattern(&mut arm.pattern)?;
+            if let Some(guard) = &mut arm.guard {
+                self.resolve_expr(guard)?;
+            }
+            self.resolve_stmt(&mut arm.body)?;
+            self.end_scope()?;
+
+            exhausted |= arm.guard.is_none() && arm.pattern.is_irrefutable();
+        }
+        Ok(())
+    }
 }
 
 impl ExprVisitor<()> for Resolver<'_> {
@@ -221,6 +362,7 @@ impl ExprVisitor<()> for Resolver<'_> {
     }
 
     fn visit_assign_expr(&mut self, expr: &mut AssignExpr) -> Result<(), RuntimeError> {
+        self.check_not_const(&expr.name)?;
         self.resolve_expr(&mut expr.value)?;
         self.resolve_local(Expr::Assign(expr.clone()), &expr.name)?;
         Ok(())
@@ -237,6 +379,9 @@ impl ExprVisitor<()> for Resolver<'_> {
         for arg in &mut expr.arguments {
             self.resolve_expr(arg)?;
         }
+        for (_, arg) in &mut expr.named_arguments {
+            self.resolve_expr(arg)?;
+        }
 
         Ok(())
     }
@@ -262,6 +407,7 @@ impl ExprVisitor<()> for Resolver<'_> {
         &mut self,
         expr: &mut PropertyAccessExpr,
     ) -> Result<(), RuntimeError> {
+        self.check_private_access(&expr.object, &mut expr.name)?;
         self.resolve_expr(&mut expr.object)
     }
 
@@ -269,6 +415,7 @@ impl ExprVisitor<()> for Resolver<'_> {
         &mut self,
         expr: &mut PropertyAssignmentExpr,
     ) -> Result<(), RuntimeError> {
+        self.check_private_access(&expr.object, &mut expr.name)?;
         expr.value.accept(self)?;
         expr.object.accept(self)?;
         Ok(())
@@ -293,6 +440,11 @@ impl ExprVisitor<()> for Resolver<'_> {
             ))
         } else if let ClassType::Subclass = self.current_class {
             self.resolve_local(Expr::Super(expr.clone()), &expr.keyword)
+        } else if let ClassType::Trait = self.current_class {
+            Err(RuntimeError::resolver_error(
+                expr.keyword.line(),
+                "Can't use 'super' in a trait.",
+            ))
         } else {
             Err(RuntimeError::resolver_error(
                 expr.keyword.line(),
@@ -300,6 +452,62 @@ impl ExprVisitor<()> for Resolver<'_> {
             ))
         }
     }
+
+    fn visit_list_expr(&mut self, expr: &mut ListExpr) -> Result<(), RuntimeError> {
+        for element in &mut expr.elements {
+            self.resolve_expr(element)?;
+        }
+        Ok(())
+    }
+
+    fn visit_index_expr(&mut self, expr: &mut IndexExpr) -> Result<(), RuntimeError> {
+        self.resolve_expr(&mut expr.object)?;
+        self.resolve_expr(&mut expr.index)
+    }
+
+    fn visit_index_assignment_expr(
+        &mut self,
+        expr: &mut IndexAssignmentExpr,
+    ) -> Result<(), RuntimeError> {
+        self.resolve_expr(&mut expr.value)?;
+        self.resolve_expr(&mut expr.object)?;
+        self.resolve_expr(&mut expr.index)
+    }
+
+    fn visit_map_expr(&mut self, expr: &mut MapExpr) -> Result<(), RuntimeError> {
+        for (key, value) in &mut expr.entries {
+            self.resolve_expr(key)?;
+            self.resolve_expr(value)?;
+        }
+        Ok(())
+    }
+
+    fn visit_ternary_expr(&mut self, expr: &mut TernaryExpr) -> Result<(), RuntimeError> {
+        self.resolve_expr(&mut expr.condition)?;
+        self.resolve_expr(&mut expr.then_branch)?;
+        self.resolve_expr(&mut expr.else_branch)
+    }
+
+    fn visit_interpolation_expr(
+        &mut self,
+        expr: &mut InterpolationExpr,
+    ) -> Result<(), RuntimeError> {
+        for part in &mut expr.parts {
+            self.resolve_expr(part)?;
+        }
+        Ok(())
+    }
+
+    fn visit_update_expr(&mut self, expr: &mut UpdateExpr) -> Result<(), RuntimeError> {
+        if let Expr::Variable(variable) = &*expr.target {
+            self.check_not_const(&variable.name)?;
+        }
+        self.resolve_expr(&mut expr.target)
+    }
+
+    fn visit_lambda_expr(&mut self, expr: &mut LambdaExpr) -> Result<(), RuntimeError> {
+        self.resolve_function(&mut expr.declaration, FunctionType::Function)
+    }
 }
 
 impl Resolver<'_> {
@@ -322,15 +530,36 @@ impl Resolver<'_> {
         expr.accept(self)
     }
 
+    /// Resolves the class names and literals of a pattern and declares the variables it binds.
+    fn resolve_pattern(&mut self, pattern: &mut Pattern) -> Result<(), RuntimeError> {
+        match pattern {
+            Pattern::Literal(literal) => self.resolve_expr(literal),
+            Pattern::Wildcard => Ok(()),
+            Pattern::Binding(name) => self.declare(name, true),
+            Pattern::Alternatives(patterns) => patterns
+                .iter_mut()
+                .try_for_each(|pattern| self.resolve_pattern(pattern)),
+            Pattern::Class(class) => {
+                self.visit_variable_expr(&class.class)?;
+                class
+                    .fields
+                    .iter_mut()
+                    .try_for_each(|(_, pattern)| self.resolve_pattern(pattern))
+            }
+        }
+    }
+
     /// Begins a new variable scope by pushing a new HashMap onto the scope stack.
     fn begin_scope(&mut self) -> Result<(), RuntimeError> {
         self.scopes.push(HashMap::new());
+        self.constants.push(HashMap::new());
         Ok(())
     }
 
     /// Ends the current variable scope by popping the last HashMap from the scope stack.
     fn end_scope(&mut self) -> Result<(), RuntimeError> {
         self.scopes.pop();
+        self.constants.pop();
         Ok(())
     }
 
@@ -375,6 +604,103 @@ impl Resolver<'_> {
         Ok(())
     }
 
+    /// Reports an error if `name` refers to a variable declared with `const`.
+    fn check_not_const(&self, name: &Token) -> Result<(), RuntimeError> {
+        let declaration = match self
+            .scopes
+            .iter()
+            .rposition(|scope| scope.contains_key(name.lexeme()))
+        {
+            Some(index) => self.constants[index].get(name.lexeme()),
+            None => self.global_constants.get(name.lexeme()),
+        };
+
+        match declaration {
+            Some(declaration) => Err(RuntimeError::resolver_error(
+                name.line(),
+                format!(
+                    "Cannot assign to constant '{}', declared on line {}.",
+                    name.lexeme(),
+                    declaration.line()
+                ),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks that the private member `name` is accessed as `this.#name` inside a class
+    /// body, and qualifies it with the name of that class.
+    fn check_private_access(&self, object: &Expr, name: &mut Token) -> Result<(), RuntimeError> {
+        // names that were already qualified start with the class name
+        if *name.token_type() != TokenType::PrivateName || !name.lexeme().starts_with('#') {
+            return Ok(());
+        }
+
+        match (object, &self.class_name) {
+            (Expr::This(_), Some(_)) => {
+                *name = self.private_name(name);
+                Ok(())
+            }
+            _ => Err(RuntimeError::resolver_error(
+                name.line(),
+                format!(
+                    "Private member '{}' can only be accessed through 'this' inside its class.",
+                    name.lexeme()
+                ),
+            )),
+        }
+    }
+
+    /// Qualifies a private member with the declaration of the class being resolved, so
+    /// subclasses, even ones with the same name, can neither read it nor override it.
+    fn private_name(&self, name: &Token) -> Token {
+        let key = match &self.class_name {
+            Some(class) => private_key(class, name.lexeme()),
+            None => name.lexeme().to_string(),
+        };
+        Token::new(
+            TokenType::PrivateName,
+            &key,
+            None,
+            name.line(),
+            name.token_number(),
+        )
+    }
+
+    /// Resolves the traits a class mixes in, and rejects methods that more than one of
+    /// them provides unless the class defines the method itself.
+    fn resolve_traits(&mut self, stmt: &ClassStmt) -> Result<(), RuntimeError> {
+        for t in &stmt.traits {
+            self.resolve_expr(&mut Expr::Variable(t.clone()))?;
+        }
+
+        let mut providers: HashMap<&str, &str> = HashMap::new();
+        for t in &stmt.traits {
+            let Some(methods) = self.traits.get(t.name.lexeme()) else {
+                continue;
+            };
+            for method in methods {
+                if stmt.methods.iter().any(|m| m.name.lexeme() == method) {
+                    continue;
+                }
+                if let Some(other) = providers.insert(method, t.name.lexeme()) {
+                    return Err(RuntimeError::resolver_error(
+                        t.name.line(),
+                        format!(
+                            "Method '{}' is provided by both trait '{}' and trait '{}', class '{}' has to define it.",
+                            method,
+                            other,
+                            t.name.lexeme(),
+                            stmt.name.lexeme()
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     fn resolve_function(
         &mut self,
         function: &mut FunctionStmt,
@@ -384,9 +710,13 @@ impl Resolver<'_> {
         self.current_function = fn_type;
 
         self.begin_scope()?;
-        for param in function.params.iter() {
-            self.declare(param, false)?;
-            self.declare(param, true)?;
+        for param in function.params.iter_mut() {
+            // defaults are evaluated in the function's scope and can use the earlier parameters
+            if let Some(default) = &mut param.default {
+                self.resolve_expr(default)?;
+            }
+            self.declare(&param.name, false)?;
+            self.declare(&param.name, true)?;
         }
         self.begin_scope()?;
         self.resolve_stmts(&mut function.body)?;
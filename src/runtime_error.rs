@@ -1,14 +1,39 @@
+use crate::class::{ClassInstance, ClassObject};
 use crate::object::Object;
+use std::collections::HashMap;
 
 /// Represents errors that can occur during the runtime execution of the code.
 #[derive(Debug, Clone)]
 pub enum RuntimeError {
-    TypeError { line: usize, message: String },
-    DivisionByZero { line: usize, message: String },
-    UndefinedVariable { line: usize, message: String },
-    ArgumentError { line: usize, message: String },
-    Resolver { line: usize, message: String },
-    Other { line: usize, message: String },
+    TypeError {
+        line: usize,
+        message: String,
+    },
+    DivisionByZero {
+        line: usize,
+        message: String,
+    },
+    UndefinedVariable {
+        line: usize,
+        message: String,
+    },
+    ArgumentError {
+        line: usize,
+        message: String,
+    },
+    Resolver {
+        line: usize,
+        message: String,
+    },
+    Other {
+        line: usize,
+        message: String,
+    },
+    /// A value thrown by a `throw` statement.
+    Throw {
+        line: usize,
+        value: Object,
+    },
     Return(Option<Object>),
     Break,
     Continue,
@@ -52,6 +77,60 @@ impl RuntimeError {
             message: message.into(),
         }
     }
+
+    /// Sets the line of an error that was raised without one, like the errors of native
+    /// functions, which don't know where they were called from.
+    pub fn or_line(mut self, call_line: usize) -> Self {
+        match &mut self {
+            RuntimeError::TypeError { line, .. }
+            | RuntimeError::DivisionByZero { line, .. }
+            | RuntimeError::UndefinedVariable { line, .. }
+            | RuntimeError::ArgumentError { line, .. }
+            | RuntimeError::Resolver { line, .. }
+            | RuntimeError::Other { line, .. }
+            | RuntimeError::Throw { line, .. }
+                if *line == 0 =>
+            {
+                *line = call_line
+            }
+            _ => (),
+        }
+        self
+    }
+
+    /// Converts the error into the value bound by a `catch` clause.
+    ///
+    /// Thrown values are returned as they are, built-in errors become instances of
+    /// `error_class` with the fields `kind`, `message` and `line`. Returns `None` for
+    /// `return`, `break` and `continue`, which cannot be caught.
+    pub fn into_value(self, error_class: &ClassObject) -> Option<Object> {
+        let (kind, line, message) = match self {
+            RuntimeError::Throw { value, .. } => return Some(value),
+            RuntimeError::TypeError { line, message } => ("TypeError", line, message),
+            RuntimeError::DivisionByZero { line, message } => ("DivisionByZero", line, message),
+            RuntimeError::UndefinedVariable { line, message } => {
+                ("UndefinedVariable", line, message)
+            }
+            RuntimeError::ArgumentError { line, message } => ("ArgumentError", line, message),
+            RuntimeError::Resolver { line, message } => ("ResolverError", line, message),
+            RuntimeError::Other { line, message } => ("RuntimeError", line, message),
+            RuntimeError::Return(_) | RuntimeError::Break | RuntimeError::Continue => return None,
+        };
+
+        let mut fields = HashMap::new();
+        fields.insert("kind".to_string(), Object::String(kind.to_string()));
+        fields.insert("message".to_string(), Object::String(message));
+        fields.insert("line".to_string(), Object::Integer(line as i64));
+        Some(Object::ClassInstance(ClassInstance::with_fields(
+            error_class.clone(),
+            fields,
+        )))
+    }
+
+    /// Creates the global `Error` class, which every caught built-in error is an instance of.
+    pub fn error_class() -> ClassObject {
+        ClassObject::new("Error", None, Vec::new(), HashMap::new())
+    }
 }
 
 impl std::fmt::Display for RuntimeError {
@@ -87,6 +166,17 @@ impl std::fmt::Display for RuntimeError {
                 "\x1b[31;49;1m[line: {}] Runtime Error: {}\x1b[0m",
                 line, message
             ),
+            RuntimeError::Throw { line, value } => {
+                write!(f, "\x1b[31;49;1m[line: {}] Uncaught error: ", line)?;
+                match value {
+                    Object::ClassInstance(instance) => match instance.field("message") {
+                        Some(message) => write!(f, "{}: {}", instance, message)?,
+                        None => write!(f, "{}", instance)?,
+                    },
+                    _ => write!(f, "{}", value)?,
+                }
+                write!(f, "\x1b[0m")
+            }
             RuntimeError::Return(_) => write!(f, "<return>"),
             RuntimeError::Break => write!(f, "<break>"),
             RuntimeError::Continue => write!(f, "<continue>"),
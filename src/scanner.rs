@@ -4,6 +4,14 @@ use crate::{
     token::{Token, TokenType},
 };
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The first token number handed out by the next call to `tokenize`.
+///
+/// The resolver identifies expressions by their token numbers, so they have to stay
+/// unique across every source the interpreter sees, including imported modules.
+static NEXT_TOKEN_NUMBER: AtomicUsize = AtomicUsize::new(1);
+
 /// Tokenizes the input string into a vector of `Token` structs.
 ///
 /// This function iterates through the input string character by character,
@@ -31,7 +39,13 @@ use crate::{
 pub fn tokenize(input: &str) -> Result<Vec<Token>, ScannerError> {
     let mut out: Vec<Token> = Vec::new();
     let mut line_number: usize = 1;
-    let mut token_number: usize = 1;
+    // every character advances the token number by at most one, so reserve one
+    // number per character plus one for the trailing EOF token
+    let mut token_number: usize =
+        NEXT_TOKEN_NUMBER.fetch_add(input.chars().count() + 1, Ordering::Relaxed);
+
+    // open `${` interpolations, each with the number of unclosed braces inside of it
+    let mut interpolations: Vec<usize> = Vec::new();
 
     let mut chars = input.chars();
     while let Some(char) = chars.next() {
@@ -50,16 +64,63 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>, ScannerError> {
                 line_number,
                 token_number,
             )),
-            '{' => out.push(Token::new(
-                TokenType::LeftBrace,
-                "{",
+            '{' => {
+                if let Some(depth) = interpolations.last_mut() {
+                    *depth += 1;
+                }
+                out.push(Token::new(
+                    TokenType::LeftBrace,
+                    "{",
+                    None,
+                    line_number,
+                    token_number,
+                ))
+            }
+            '}' => match interpolations.last_mut() {
+                // closes an embedded expression, the string continues after it
+                Some(0) => {
+                    interpolations.pop();
+                    out.push(Token::new(
+                        TokenType::InterpolationEnd,
+                        "}",
+                        None,
+                        line_number,
+                        token_number,
+                    ));
+                    let (string_literal, interpolated) = scan_string(&mut chars, &mut line_number)?;
+                    if interpolated {
+                        interpolations.push(0);
+                    }
+                    out.push(string_token(
+                        string_literal,
+                        interpolated,
+                        line_number,
+                        token_number,
+                    ));
+                }
+                depth => {
+                    if let Some(depth) = depth {
+                        *depth -= 1;
+                    }
+                    out.push(Token::new(
+                        TokenType::RightBrace,
+                        "}",
+                        None,
+                        line_number,
+                        token_number,
+                    ))
+                }
+            },
+            '[' => out.push(Token::new(
+                TokenType::LeftBracket,
+                "[",
                 None,
                 line_number,
                 token_number,
             )),
-            '}' => out.push(Token::new(
-                TokenType::RightBrace,
-                "}",
+            ']' => out.push(Token::new(
+                TokenType::RightBracket,
+                "]",
                 None,
                 line_number,
                 token_number,
@@ -71,41 +132,246 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>, ScannerError> {
                 line_number,
                 token_number,
             )),
-            '.' => out.push(Token::new(
-                TokenType::Dot,
-                ".",
-                None,
-                line_number,
-                token_number,
-            )),
-            '-' => out.push(Token::new(
-                TokenType::Minus,
-                "-",
-                None,
-                line_number,
-                token_number,
-            )),
-            '+' => out.push(Token::new(
-                TokenType::Plus,
-                "+",
+            '.' => {
+                let mut peek = chars.clone().peekable();
+                match (peek.next(), peek.next()) {
+                    (Some('.'), Some('.')) => {
+                        out.push(Token::new(
+                            TokenType::Ellipsis,
+                            "...",
+                            None,
+                            line_number,
+                            token_number,
+                        ));
+                        chars.next();
+                        chars.next();
+                    }
+                    (Some('.'), Some('=')) => {
+                        out.push(Token::new(
+                            TokenType::DotDotEqual,
+                            "..=",
+                            None,
+                            line_number,
+                            token_number,
+                        ));
+                        chars.next();
+                        chars.next();
+                    }
+                    (Some('.'), _) => {
+                        out.push(Token::new(
+                            TokenType::DotDot,
+                            "..",
+                            None,
+                            line_number,
+                            token_number,
+                        ));
+                        chars.next();
+                    }
+                    _ => out.push(Token::new(
+                        TokenType::Dot,
+                        ".",
+                        None,
+                        line_number,
+                        token_number,
+                    )),
+                }
+            }
+            '-' => {
+                let mut peek = chars.clone().peekable();
+                match peek.next() {
+                    Some('-') => {
+                        out.push(Token::new(
+                            TokenType::MinusMinus,
+                            "--",
+                            None,
+                            line_number,
+                            token_number,
+                        ));
+                        chars.next();
+                    }
+                    Some('=') => {
+                        out.push(Token::new(
+                            TokenType::MinusEqual,
+                            "-=",
+                            None,
+                            line_number,
+                            token_number,
+                        ));
+                        chars.next();
+                    }
+                    _ => out.push(Token::new(
+                        TokenType::Minus,
+                        "-",
+                        None,
+                        line_number,
+                        token_number,
+                    )),
+                }
+            }
+            '+' => {
+                let mut peek = chars.clone().peekable();
+                match peek.next() {
+                    Some('+') => {
+                        out.push(Token::new(
+                            TokenType::PlusPlus,
+                            "++",
+                            None,
+                            line_number,
+                            token_number,
+                        ));
+                        chars.next();
+                    }
+                    Some('=') => {
+                        out.push(Token::new(
+                            TokenType::PlusEqual,
+                            "+=",
+                            None,
+                            line_number,
+                            token_number,
+                        ));
+                        chars.next();
+                    }
+                    _ => out.push(Token::new(
+                        TokenType::Plus,
+                        "+",
+                        None,
+                        line_number,
+                        token_number,
+                    )),
+                }
+            }
+            ';' => out.push(Token::new(
+                TokenType::Semicolon,
+                ";",
                 None,
                 line_number,
                 token_number,
             )),
-            ';' => out.push(Token::new(
-                TokenType::Semicolon,
-                ";",
+            ':' => out.push(Token::new(
+                TokenType::Colon,
+                ":",
                 None,
                 line_number,
                 token_number,
             )),
-            '*' => out.push(Token::new(
-                TokenType::Asterisk,
-                "*",
+            '#' => {
+                let mut name = String::from(char);
+                while let Some(next) = chars.clone().next() {
+                    if is_valid_variable_char(next, name.len() == 1) {
+                        name.push(next);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+
+                if name.len() == 1 {
+                    return Err(ScannerError::InvalidSyntax(
+                        line_number,
+                        "Expected a name after '#'".to_string(),
+                    ));
+                }
+                out.push(Token::new(
+                    TokenType::PrivateName,
+                    &name,
+                    None,
+                    line_number,
+                    token_number,
+                ));
+            }
+            '?' => {
+                let mut peek = chars.clone().peekable();
+                match peek.next() {
+                    Some('?') => {
+                        out.push(Token::new(
+                            TokenType::QuestionQuestion,
+                            "??",
+                            None,
+                            line_number,
+                            token_number,
+                        ));
+                        chars.next();
+                    }
+                    Some('.') => {
+                        out.push(Token::new(
+                            TokenType::QuestionDot,
+                            "?.",
+                            None,
+                            line_number,
+                            token_number,
+                        ));
+                        chars.next();
+                    }
+                    _ => out.push(Token::new(
+                        TokenType::Question,
+                        "?",
+                        None,
+                        line_number,
+                        token_number,
+                    )),
+                }
+            }
+            '^' => out.push(Token::new(
+                TokenType::BitwiseXor,
+                "^",
                 None,
                 line_number,
                 token_number,
             )),
+            '%' => {
+                let mut peek = chars.clone().peekable();
+                match peek.next() {
+                    Some('=') => {
+                        out.push(Token::new(
+                            TokenType::PercentEqual,
+                            "%=",
+                            None,
+                            line_number,
+                            token_number,
+                        ));
+                        chars.next();
+                    }
+                    _ => out.push(Token::new(
+                        TokenType::Percent,
+                        "%",
+                        None,
+                        line_number,
+                        token_number,
+                    )),
+                }
+            }
+            '*' => {
+                let mut peek = chars.clone().peekable();
+                match peek.next() {
+                    Some('*') => {
+                        out.push(Token::new(
+                            TokenType::StarStar,
+                            "**",
+                            None,
+                            line_number,
+                            token_number,
+                        ));
+                        chars.next();
+                    }
+                    Some('=') => {
+                        out.push(Token::new(
+                            TokenType::StarEqual,
+                            "*=",
+                            None,
+                            line_number,
+                            token_number,
+                        ));
+                        chars.next();
+                    }
+                    _ => out.push(Token::new(
+                        TokenType::Asterisk,
+                        "*",
+                        None,
+                        line_number,
+                        token_number,
+                    )),
+                }
+            }
             '!' => {
                 let mut peek = chars.clone().peekable();
                 if peek.next() == Some('=') {
@@ -129,7 +395,8 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>, ScannerError> {
             }
             '=' => {
                 let mut peek = chars.clone().peekable();
-                if peek.next() == Some('=') {
+                let next = peek.next();
+                if next == Some('=') {
                     out.push(Token::new(
                         TokenType::EqualEqual,
                         "==",
@@ -138,6 +405,15 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>, ScannerError> {
                         token_number,
                     ));
                     chars.next();
+                } else if next == Some('>') {
+                    out.push(Token::new(
+                        TokenType::FatArrow,
+                        "=>",
+                        None,
+                        line_number,
+                        token_number,
+                    ));
+                    chars.next();
                 } else {
                     out.push(Token::new(
                         TokenType::Equal,
@@ -150,115 +426,155 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>, ScannerError> {
             }
             '<' => {
                 let mut peek = chars.clone().peekable();
-                if peek.next() == Some('=') {
-                    out.push(Token::new(
-                        TokenType::LessEqual,
-                        "<=",
-                        None,
-                        line_number,
-                        token_number,
-                    ));
-                    chars.next();
-                } else {
-                    out.push(Token::new(
+                match peek.next() {
+                    Some('=') => {
+                        out.push(Token::new(
+                            TokenType::LessEqual,
+                            "<=",
+                            None,
+                            line_number,
+                            token_number,
+                        ));
+                        chars.next();
+                    }
+                    Some('<') => {
+                        out.push(Token::new(
+                            TokenType::ShiftLeft,
+                            "<<",
+                            None,
+                            line_number,
+                            token_number,
+                        ));
+                        chars.next();
+                    }
+                    _ => out.push(Token::new(
                         TokenType::Less,
                         "<",
                         None,
                         line_number,
                         token_number,
-                    ));
+                    )),
                 }
             }
             '>' => {
                 let mut peek = chars.clone().peekable();
-                if peek.next() == Some('=') {
-                    out.push(Token::new(
-                        TokenType::GreaterEqual,
-                        ">=",
-                        None,
-                        line_number,
-                        token_number,
-                    ));
-                    chars.next();
-                } else {
-                    out.push(Token::new(
+                match peek.next() {
+                    Some('=') => {
+                        out.push(Token::new(
+                            TokenType::GreaterEqual,
+                            ">=",
+                            None,
+                            line_number,
+                            token_number,
+                        ));
+                        chars.next();
+                    }
+                    Some('>') => {
+                        out.push(Token::new(
+                            TokenType::ShiftRight,
+                            ">>",
+                            None,
+                            line_number,
+                            token_number,
+                        ));
+                        chars.next();
+                    }
+                    _ => out.push(Token::new(
                         TokenType::Greater,
                         ">",
                         None,
                         line_number,
                         token_number,
-                    ));
+                    )),
                 }
             }
             '/' => {
                 let mut peek = chars.clone().peekable();
-                if peek.next() == Some('/') {
-                    // if we find a comment  loop until the next line
-                    for char in chars.by_ref() {
-                        if char == '\n' {
-                            line_number += 1;
-                            break;
+                match peek.next() {
+                    Some('/') => {
+                        // if we find a comment  loop until the next line
+                        for char in chars.by_ref() {
+                            if char == '\n' {
+                                line_number += 1;
+                                break;
+                            }
                         }
                     }
-                } else {
-                    out.push(Token::new(
+                    Some('=') => {
+                        out.push(Token::new(
+                            TokenType::SlashEqual,
+                            "/=",
+                            None,
+                            line_number,
+                            token_number,
+                        ));
+                        chars.next();
+                    }
+                    _ => out.push(Token::new(
                         TokenType::Slash,
                         "/",
                         None,
                         line_number,
                         token_number,
+                    )),
+                }
+            }
+            // `//` already starts a comment, so floor division is spelled `~/`
+            '~' => {
+                let mut peek = chars.clone().peekable();
+                if peek.next() == Some('/') {
+                    out.push(Token::new(
+                        TokenType::TildeSlash,
+                        "~/",
+                        None,
+                        line_number,
+                        token_number,
+                    ));
+                    chars.next();
+                } else {
+                    out.push(Token::new(
+                        TokenType::BitwiseNot,
+                        "~",
+                        None,
+                        line_number,
+                        token_number,
                     ));
                 }
             }
             '\n' => line_number += 1,
             ' ' => continue,
             '\t' => continue,
+            '"' if chars.as_str().starts_with("\"\"") => {
+                chars.nth(1);
+                let string_literal = scan_multiline_string(&mut chars, &mut line_number)?;
+                out.push(string_token(
+                    string_literal,
+                    false,
+                    line_number,
+                    token_number,
+                ));
+            }
+            'r' if chars.as_str().starts_with('"') => {
+                chars.next();
+                let string_literal = scan_raw_string(&mut chars, &mut line_number)?;
+                out.push(string_token(
+                    string_literal,
+                    false,
+                    line_number,
+                    token_number,
+                ));
+            }
             '"' => {
-                let mut string_literal = String::new();
-                let mut escaped = false;
-
-                loop {
-                    if let Some(char) = chars.next() {
-                        if escaped {
-                            match char {
-                                '"' => string_literal.push('"'),
-                                '\\' => string_literal.push('\\'),
-                                'n' => string_literal.push('\n'),
-                                't' => string_literal.push('\t'),
-                                'r' => string_literal.push('\r'),
-                                _ => {
-                                    string_literal.push('\\');
-                                    string_literal.push(char);
-                                }
-                            }
-                            escaped = false;
-                        } else if char == '\\' {
-                            escaped = true;
-                        } else if char == '"' {
-                            // Create string object here when we have Object impl
-                            let value = Some(Object::String(string_literal.clone()));
-                            out.push(Token::new(
-                                TokenType::String,
-                                &format!("\"{}\"", string_literal),
-                                value,
-                                line_number,
-                                token_number,
-                            ));
-                            break;
-                        } else {
-                            string_literal.push(char);
-
-                            if char == '\n' {
-                                line_number += 1;
-                            }
-                        }
-                    } else {
-                        return Err(ScannerError::InvalidSyntax(
-                            line_number,
-                            "Unterminated string".to_string(),
-                        ));
-                    }
+                let (string_literal, interpolated) = scan_string(&mut chars, &mut line_number)?;
+                if interpolated {
+                    interpolations.push(0);
                 }
+                out.push(string_token(
+                    string_literal,
+                    interpolated,
+                    line_number,
+                    token_number,
+                ));
             }
             '&' => {
                 let mut peek = chars.clone().peekable();
@@ -303,28 +619,11 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>, ScannerError> {
                 }
             }
             '0'..='9' => {
-                let mut number_literal = String::from(char);
-                let mut had_comma: bool = false;
-
-                while let Some(char) = chars.clone().peekable().peek() {
-                    if char.is_ascii_digit() {
-                        number_literal.push(*char);
-                        chars.next();
-                    } else if *char == '.' && !had_comma {
-                        had_comma = true;
-                        number_literal.push(*char);
-                        chars.next();
-                    } else {
-                        break;
-                    }
-                }
-
-                // Create number object here when we have Object impl
-                let value = Some(Object::Number(number_literal.parse().unwrap_or(0.0)));
+                let (number_literal, value) = scan_number(char, &mut chars, line_number)?;
                 out.push(Token::new(
                     TokenType::Number,
                     &number_literal,
-                    value,
+                    Some(value),
                     line_number,
                     token_number,
                 ));
@@ -531,6 +830,97 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>, ScannerError> {
                             line_number,
                             token_number,
                         )),
+                        "const" => out.push(Token::new(
+                            TokenType::Const,
+                            "const",
+                            None,
+                            line_number,
+                            token_number,
+                        )),
+                        "import" => out.push(Token::new(
+                            TokenType::Import,
+                            "import",
+                            None,
+                            line_number,
+                            token_number,
+                        )),
+                        "as" => out.push(Token::new(
+                            TokenType::As,
+                            "as",
+                            None,
+                            line_number,
+                            token_number,
+                        )),
+                        "in" => out.push(Token::new(
+                            TokenType::In,
+                            "in",
+                            None,
+                            line_number,
+                            token_number,
+                        )),
+                        "try" => out.push(Token::new(
+                            TokenType::Try,
+                            "try",
+                            None,
+                            line_number,
+                            token_number,
+                        )),
+                        "catch" => out.push(Token::new(
+                            TokenType::Catch,
+                            "catch",
+                            None,
+                            line_number,
+                            token_number,
+                        )),
+                        "finally" => out.push(Token::new(
+                            TokenType::Finally,
+                            "finally",
+                            None,
+                            line_number,
+                            token_number,
+                        )),
+                        "throw" => out.push(Token::new(
+                            TokenType::Throw,
+                            "throw",
+                            None,
+                            line_number,
+                            token_number,
+                        )),
+                        "match" => out.push(Token::new(
+                            TokenType::Match,
+                            "match",
+                            None,
+                            line_number,
+                            token_number,
+                        )),
+                        "is" => out.push(Token::new(
+                            TokenType::Is,
+                            "is",
+                            None,
+                            line_number,
+                            token_number,
+                        )),
+                        "trait" => out.push(Token::new(
+                            TokenType::Trait,
+                            "trait",
+                            None,
+                            line_number,
+                            token_number,
+                        )),
+                        "with" => out.push(Token::new(
+                            TokenType::With,
+                            "with",
+                            None,
+                            line_number,
+                            token_number,
+                        )),
+                        "instanceof" => out.push(Token::new(
+                            TokenType::Instanceof,
+                            "instanceof",
+                            None,
+                            line_number,
+                            token_number,
+                        )),
                         _ => {
                             out.push(Token::new(
                                 TokenType::Var,
@@ -562,6 +952,13 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>, ScannerError> {
         }
         token_number += 1;
     }
+    if !interpolations.is_empty() {
+        return Err(ScannerError::InvalidSyntax(
+            line_number,
+            "Unterminated string interpolation".to_string(),
+        ));
+    }
+
     out.push(Token::new(
         TokenType::Eof,
         "",
@@ -573,6 +970,328 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>, ScannerError> {
     Ok(out)
 }
 
+/// Scans a number literal starting with the digit `first` and returns its lexeme and value.
+///
+/// Accepts decimal literals with an optional fraction and exponent (`6.02E23`, `1e-9`),
+/// hexadecimal (`0xFF`), binary (`0b1010`) and octal (`0o755`) integers, and `_` between
+/// digits as a separator (`1_000_000`). Literals without a fraction or exponent are
+/// integers.
+fn scan_number(
+    first: char,
+    chars: &mut std::str::Chars,
+    line_number: usize,
+) -> Result<(String, Object), ScannerError> {
+    let mut number_literal = String::from(first);
+    let radix = match (first, chars.clone().next()) {
+        ('0', Some('x' | 'X')) => 16,
+        ('0', Some('b' | 'B')) => 2,
+        ('0', Some('o' | 'O')) => 8,
+        _ => 10,
+    };
+
+    while let Some(char) = chars.clone().next() {
+        let exponent_sign =
+            radix == 10 && matches!(char, '+' | '-') && number_literal.ends_with(['e', 'E']);
+        // a dot that is not followed by a digit starts a range (`0..10`) or a property access
+        let fraction = char == '.' && chars.clone().nth(1).is_some_and(|c| c.is_ascii_digit());
+        if char.is_alphanumeric() || char == '_' || exponent_sign || fraction {
+            number_literal.push(char);
+            chars.next();
+        } else {
+            break;
+        }
+    }
+
+    let invalid = || {
+        ScannerError::InvalidSyntax(
+            line_number,
+            format!("Invalid number literal: {}", number_literal),
+        )
+    };
+    let out_of_range = || {
+        ScannerError::InvalidSyntax(
+            line_number,
+            format!("Integer literal out of range: {}", number_literal),
+        )
+    };
+
+    // separators have to sit between two digits
+    let literal: Vec<char> = number_literal.chars().collect();
+    for (i, char) in literal.iter().enumerate() {
+        if *char == '_'
+            && !(i > 0
+                && literal[i - 1].is_digit(radix)
+                && literal.get(i + 1).is_some_and(|c| c.is_digit(radix)))
+        {
+            return Err(invalid());
+        }
+    }
+    let digits = number_literal.replace('_', "");
+
+    if radix != 10 {
+        let digits = &digits[2..];
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return Err(invalid());
+        }
+        return i64::from_str_radix(digits, radix)
+            .map(|int| (number_literal.clone(), Object::Integer(int)))
+            .map_err(|_| out_of_range());
+    }
+
+    // `digits [. digits] [(e|E) [+|-] digits]`
+    let (mantissa, exponent) = match digits.find(['e', 'E']) {
+        Some(index) => (&digits[..index], Some(&digits[index + 1..])),
+        None => (digits.as_str(), None),
+    };
+    let (integral, fraction) = match mantissa.split_once('.') {
+        Some((integral, fraction)) => (integral, Some(fraction)),
+        None => (mantissa, None),
+    };
+    let all_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    let valid = all_digits(integral)
+        && fraction.is_none_or(all_digits)
+        && exponent.is_none_or(|e| all_digits(e.strip_prefix(['+', '-']).unwrap_or(e)));
+    if !valid {
+        return Err(invalid());
+    }
+
+    let value = if fraction.is_some() || exponent.is_some() {
+        Object::Number(digits.parse().map_err(|_| invalid())?)
+    } else {
+        Object::Integer(digits.parse().map_err(|_| out_of_range())?)
+    };
+    Ok((number_literal, value))
+}
+
+/// Scans the rest of a string literal, after its opening quote or after the `}` that
+/// closes an embedded expression.
+///
+/// Returns the unescaped contents and whether the string stopped at a `${`, in which
+/// case an embedded expression follows instead of the closing quote.
+fn scan_string(
+    chars: &mut std::str::Chars,
+    line_number: &mut usize,
+) -> Result<(String, bool), ScannerError> {
+    let mut string_literal = String::new();
+
+    loop {
+        if let Some(char) = chars.next() {
+            if char == '\\' {
+                unescape(&mut string_literal, chars, *line_number)?;
+            } else if char == '"' {
+                return Ok((string_literal, false));
+            } else if char == '$' && chars.clone().next() == Some('{') {
+                chars.next();
+                return Ok((string_literal, true));
+            } else {
+                string_literal.push(char);
+
+                if char == '\n' {
+                    *line_number += 1;
+                }
+            }
+        } else {
+            return Err(ScannerError::InvalidSyntax(
+                *line_number,
+                "Unterminated string".to_string(),
+            ));
+        }
+    }
+}
+
+/// Scans the rest of a raw string (`r"..."`), after its opening quote.
+///
+/// Backslashes have no special meaning in raw strings, so they can't contain a `"`.
+fn scan_raw_string(
+    chars: &mut std::str::Chars,
+    line_number: &mut usize,
+) -> Result<String, ScannerError> {
+    let mut string_literal = String::new();
+
+    for char in chars.by_ref() {
+        if char == '"' {
+            return Ok(string_literal);
+        }
+        if char == '\n' {
+            *line_number += 1;
+        }
+        string_literal.push(char);
+    }
+
+    Err(ScannerError::InvalidSyntax(
+        *line_number,
+        "Unterminated raw string".to_string(),
+    ))
+}
+
+/// Scans the rest of a triple-quoted string (`"""..."""`), after its opening quotes.
+///
+/// A line break directly after the opening quotes and the whitespace before the closing
+/// quotes are dropped, and the indentation shared by all non-blank lines is removed, so
+/// the string can be indented along with the surrounding code. Escapes are processed
+/// after the indentation is removed.
+fn scan_multiline_string(
+    chars: &mut std::str::Chars,
+    line_number: &mut usize,
+) -> Result<String, ScannerError> {
+    let start_line = *line_number;
+    let mut raw = String::new();
+
+    loop {
+        match chars.next() {
+            Some('"') if chars.as_str().starts_with("\"\"") => {
+                chars.nth(1);
+                break;
+            }
+            // keep escapes as they are, but don't let `\"` end the string
+            Some('\\') => {
+                raw.push('\\');
+                if let Some(char) = chars.next() {
+                    raw.push(char);
+                }
+            }
+            Some(char) => raw.push(char),
+            None => {
+                return Err(ScannerError::InvalidSyntax(
+                    *line_number,
+                    "Unterminated multi-line string".to_string(),
+                ))
+            }
+        }
+    }
+    *line_number += raw.matches('\n').count();
+
+    let mut lines: Vec<&str> = raw.split('\n').collect();
+    if lines.len() > 1 && lines[0].trim().is_empty() {
+        lines.remove(0);
+    }
+    if lines.len() > 1 && lines[lines.len() - 1].trim().is_empty() {
+        lines.pop();
+    }
+    // only spaces and tabs count as indentation, so slicing them off stays on char boundaries
+    let leading = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| leading(line))
+        .min()
+        .unwrap_or(0);
+    let dedented = lines
+        .iter()
+        .map(|line| line[leading(line).min(indent)..].trim_end_matches('\r'))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut string_literal = String::new();
+    let mut dedented = dedented.chars();
+    while let Some(char) = dedented.next() {
+        if char == '\\' {
+            unescape(&mut string_literal, &mut dedented, start_line)?;
+        } else {
+            string_literal.push(char);
+        }
+    }
+    Ok(string_literal)
+}
+
+/// Reads the escape sequence following a backslash and pushes the character it stands for.
+///
+/// Supports `\"`, `\\`, `\$`, `\n`, `\t`, `\r`, `\x41` (two hex digits, ASCII only) and
+/// `\u{1F600}` (one to six hex digits). Unknown escapes are kept as they are.
+fn unescape(
+    string_literal: &mut String,
+    chars: &mut std::str::Chars,
+    line_number: usize,
+) -> Result<(), ScannerError> {
+    match chars.next() {
+        Some('"') => string_literal.push('"'),
+        Some('\\') => string_literal.push('\\'),
+        Some('$') => string_literal.push('$'),
+        Some('n') => string_literal.push('\n'),
+        Some('t') => string_literal.push('\t'),
+        Some('r') => string_literal.push('\r'),
+        Some('x') => {
+            let digits: String = chars.by_ref().take(2).collect();
+            match u8::from_str_radix(&digits, 16) {
+                Ok(byte) if digits.len() == 2 && byte.is_ascii() => {
+                    string_literal.push(char::from(byte))
+                }
+                // strings are UTF-8, a single byte above 0x7F isn't a character
+                Ok(byte) if digits.len() == 2 => {
+                    return Err(ScannerError::InvalidSyntax(
+                        line_number,
+                        format!(
+                            "Invalid escape sequence: \\x{} is not ASCII, use \\u{{{:X}}} instead",
+                            digits, byte
+                        ),
+                    ))
+                }
+                _ => {
+                    return Err(ScannerError::InvalidSyntax(
+                        line_number,
+                        format!("Invalid escape sequence: \\x{}", digits),
+                    ))
+                }
+            }
+        }
+        Some('u') => {
+            let invalid = |digits: &str| {
+                ScannerError::InvalidSyntax(
+                    line_number,
+                    format!("Invalid unicode escape: \\u{}", digits),
+                )
+            };
+            if chars.next() != Some('{') {
+                return Err(invalid(""));
+            }
+            let digits: String = chars.by_ref().take_while(|c| *c != '}').collect();
+            if digits.is_empty() || digits.len() > 6 {
+                return Err(invalid(&format!("{{{}}}", digits)));
+            }
+            match u32::from_str_radix(&digits, 16)
+                .ok()
+                .and_then(char::from_u32)
+            {
+                Some(char) => string_literal.push(char),
+                None => return Err(invalid(&format!("{{{}}}", digits))),
+            }
+        }
+        Some(char) => {
+            string_literal.push('\\');
+            string_literal.push(char);
+        }
+        None => {
+            return Err(ScannerError::InvalidSyntax(
+                line_number,
+                "Unterminated string".to_string(),
+            ))
+        }
+    }
+    Ok(())
+}
+
+/// Creates the token for a scanned string segment, which is an `Interpolation` token if
+/// an embedded expression follows it.
+fn string_token(
+    string_literal: String,
+    interpolated: bool,
+    line_number: usize,
+    token_number: usize,
+) -> Token {
+    let token_type = if interpolated {
+        TokenType::Interpolation
+    } else {
+        TokenType::String
+    };
+    Token::new(
+        token_type,
+        &format!("\"{}\"", string_literal),
+        Some(Object::String(string_literal)),
+        line_number,
+        token_number,
+    )
+}
+
 /// Checks if a given character is valid for use within a variable name.
 ///
 /// Valid characters include:
@@ -591,7 +1310,7 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>, ScannerError> {
 /// # Returns
 ///
 /// `true` if the character is valid in the given context, `false` otherwise.
-fn is_valid_variable_char(c: char, is_first_char: bool) -> bool {
+pub fn is_valid_variable_char(c: char, is_first_char: bool) -> bool {
     match c {
         'a'..='z' | 'A'..='Z' | '_' => true,
         '0'..='9' => !is_first_char,
@@ -611,10 +1330,35 @@ fn is_valid_variable_char(c: char, is_first_char: bool) -> bool {
 /// # Returns
 ///
 /// `true` if the string is a keyword, `false` otherwise.
-fn is_keyword(var: &str) -> bool {
+pub fn is_keyword(var: &str) -> bool {
     vec![
-        "else", "false", "for", "fun", "if", "nil", "print", "return", "super", "this", "true",
-        "var", "while", "class",
+        "else",
+        "false",
+        "for",
+        "fun",
+        "if",
+        "nil",
+        "print",
+        "return",
+        "super",
+        "this",
+        "true",
+        "var",
+        "const",
+        "while",
+        "class",
+        "import",
+        "as",
+        "in",
+        "try",
+        "catch",
+        "finally",
+        "throw",
+        "match",
+        "is",
+        "trait",
+        "with",
+        "instanceof",
     ]
     .contains(&var)
 }
@@ -642,9 +1386,9 @@ mod tests {
 
     #[test]
     fn test_simple_tokens() {
-        let input = "(){},.+-;*";
+        let input = "(){},.+-;*:";
         let tokens = tokenize(input).unwrap();
-        assert_eq!(tokens.len(), 11); // +1 for EOF
+        assert_eq!(tokens.len(), 12); // +1 for EOF
         assert!(matches!(tokens[0].token_type(), TokenType::LeftParen));
         assert!(matches!(tokens[1].token_type(), TokenType::RightParen));
         assert!(matches!(tokens[2].token_type(), TokenType::LeftBrace));
@@ -655,6 +1399,115 @@ mod tests {
         assert!(matches!(tokens[7].token_type(), TokenType::Minus));
         assert!(matches!(tokens[8].token_type(), TokenType::Semicolon));
         assert!(matches!(tokens[9].token_type(), TokenType::Asterisk));
+        assert!(matches!(tokens[10].token_type(), TokenType::Colon));
+    }
+
+    #[test]
+    fn test_arithmetic_operators() {
+        let input = "% * ** / ~/";
+        let tokens = tokenize(input).unwrap();
+        assert_eq!(tokens.len(), 6); // +1 for EOF
+        assert!(matches!(tokens[0].token_type(), TokenType::Percent));
+        assert!(matches!(tokens[1].token_type(), TokenType::Asterisk));
+        assert!(matches!(tokens[2].token_type(), TokenType::StarStar));
+        assert!(matches!(tokens[3].token_type(), TokenType::Slash));
+        assert!(matches!(tokens[4].token_type(), TokenType::TildeSlash));
+    }
+
+    #[test]
+    fn test_assignment_operators() {
+        let input = "+= -= *= /= %= ++ -- + -";
+        let tokens = tokenize(input).unwrap();
+        assert_eq!(tokens.len(), 10); // +1 for EOF
+        assert!(matches!(tokens[0].token_type(), TokenType::PlusEqual));
+        assert!(matches!(tokens[1].token_type(), TokenType::MinusEqual));
+        assert!(matches!(tokens[2].token_type(), TokenType::StarEqual));
+        assert!(matches!(tokens[3].token_type(), TokenType::SlashEqual));
+        assert!(matches!(tokens[4].token_type(), TokenType::PercentEqual));
+        assert!(matches!(tokens[5].token_type(), TokenType::PlusPlus));
+        assert!(matches!(tokens[6].token_type(), TokenType::MinusMinus));
+        assert!(matches!(tokens[7].token_type(), TokenType::Plus));
+        assert!(matches!(tokens[8].token_type(), TokenType::Minus));
+    }
+
+    #[test]
+    fn test_fat_arrow() {
+        let input = "= == => match";
+        let tokens = tokenize(input).unwrap();
+        assert_eq!(tokens.len(), 5); // +1 for EOF
+        assert!(matches!(tokens[0].token_type(), TokenType::Equal));
+        assert!(matches!(tokens[1].token_type(), TokenType::EqualEqual));
+        assert!(matches!(tokens[2].token_type(), TokenType::FatArrow));
+        assert!(matches!(tokens[3].token_type(), TokenType::Match));
+    }
+
+    #[test]
+    fn test_trait_keywords() {
+        let input = "trait Named class Dog < Animal with Named";
+        let tokens = tokenize(input).unwrap();
+        assert_eq!(tokens.len(), 9); // +1 for EOF
+        assert!(matches!(tokens[0].token_type(), TokenType::Trait));
+        assert!(matches!(tokens[1].token_type(), TokenType::Var));
+        assert!(matches!(tokens[6].token_type(), TokenType::With));
+        assert!(matches!(tokens[7].token_type(), TokenType::Var));
+    }
+
+    #[test]
+    fn test_instanceof_keyword() {
+        let input = "dog instanceof Animal instance";
+        let tokens = tokenize(input).unwrap();
+        assert_eq!(tokens.len(), 5); // +1 for EOF
+        assert!(matches!(tokens[1].token_type(), TokenType::Instanceof));
+        assert!(matches!(tokens[3].token_type(), TokenType::Var));
+    }
+
+    #[test]
+    fn test_question_operators() {
+        let input = "? ?? ?. :";
+        let tokens = tokenize(input).unwrap();
+        assert_eq!(tokens.len(), 5); // +1 for EOF
+        assert!(matches!(tokens[0].token_type(), TokenType::Question));
+        assert!(matches!(
+            tokens[1].token_type(),
+            TokenType::QuestionQuestion
+        ));
+        assert!(matches!(tokens[2].token_type(), TokenType::QuestionDot));
+        assert!(matches!(tokens[3].token_type(), TokenType::Colon));
+    }
+
+    #[test]
+    fn test_range_operators() {
+        let input = "0..10 1..=2.5 a.b";
+        let tokens = tokenize(input).unwrap();
+        assert_eq!(tokens.len(), 10); // +1 for EOF
+        assert!(matches!(tokens[0].literal(), Some(Object::Integer(0))));
+        assert!(matches!(tokens[1].token_type(), TokenType::DotDot));
+        assert!(matches!(tokens[2].literal(), Some(Object::Integer(10))));
+        assert!(matches!(tokens[3].literal(), Some(Object::Integer(1))));
+        assert!(matches!(tokens[4].token_type(), TokenType::DotDotEqual));
+        assert_eq!(tokens[5].lexeme(), "2.5");
+        assert!(matches!(tokens[7].token_type(), TokenType::Dot));
+    }
+
+    #[test]
+    fn test_ellipsis() {
+        let input = "...rest ..";
+        let tokens = tokenize(input).unwrap();
+        assert_eq!(tokens.len(), 4); // +1 for EOF
+        assert!(matches!(tokens[0].token_type(), TokenType::Ellipsis));
+        assert_eq!(tokens[1].lexeme(), "rest");
+        assert!(matches!(tokens[2].token_type(), TokenType::DotDot));
+    }
+
+    #[test]
+    fn test_brackets() {
+        let input = "[1, 2][0]";
+        let tokens = tokenize(input).unwrap();
+        assert_eq!(tokens.len(), 9); // +1 for EOF
+        assert!(matches!(tokens[0].token_type(), TokenType::LeftBracket));
+        assert!(matches!(tokens[4].token_type(), TokenType::RightBracket));
+        assert!(matches!(tokens[5].token_type(), TokenType::LeftBracket));
+        assert!(matches!(tokens[7].token_type(), TokenType::RightBracket));
     }
 
     #[test]
@@ -672,6 +1525,17 @@ mod tests {
         assert!(matches!(tokens[7].token_type(), TokenType::Bang));
     }
 
+    #[test]
+    fn test_bitwise_operators() {
+        let input = "^ ~ << >>";
+        let tokens = tokenize(input).unwrap();
+        assert_eq!(tokens.len(), 5); // +1 for EOF
+        assert!(matches!(tokens[0].token_type(), TokenType::BitwiseXor));
+        assert!(matches!(tokens[1].token_type(), TokenType::BitwiseNot));
+        assert!(matches!(tokens[2].token_type(), TokenType::ShiftLeft));
+        assert!(matches!(tokens[3].token_type(), TokenType::ShiftRight));
+    }
+
     #[test]
     fn test_logical_operators() {
         let input = "&& || & |";
@@ -694,6 +1558,90 @@ mod tests {
         assert_eq!(tokens[1].lexeme(), "\"test\"");
     }
 
+    #[test]
+    fn test_string_interpolation() {
+        let input = r#""a ${x + "${y}"} b \${c}""#;
+        let tokens = tokenize(input).unwrap();
+        let types: Vec<&TokenType> = tokens.iter().map(|t| t.token_type()).collect();
+        assert_eq!(
+            types,
+            vec![
+                &TokenType::Interpolation,
+                &TokenType::Var,
+                &TokenType::Plus,
+                &TokenType::Interpolation,
+                &TokenType::Var,
+                &TokenType::InterpolationEnd,
+                &TokenType::String,
+                &TokenType::InterpolationEnd,
+                &TokenType::String,
+                &TokenType::Eof,
+            ]
+        );
+        assert_eq!(*tokens[0].literal(), Some(Object::String("a ".to_string())));
+        assert_eq!(
+            *tokens[8].literal(),
+            Some(Object::String(" b ${c}".to_string()))
+        );
+        assert!(tokenize(r#""${x""#).is_err());
+    }
+
+    #[test]
+    fn test_string_escapes() {
+        let tokens = tokenize(r#""\u{1F600} \x41 \u{e9} \q""#).unwrap();
+        assert_eq!(
+            *tokens[0].literal(),
+            Some(Object::String("\u{1F600} A \u{e9} \\q".to_string()))
+        );
+        assert!(tokenize(r#""\u{110000}""#).is_err());
+        assert!(tokenize(r#""\u41""#).is_err());
+        assert!(tokenize(r#""\xZ1""#).is_err());
+        let error = tokenize(r#""\xE9""#).unwrap_err();
+        assert!(error.to_string().contains("use \\u{E9} instead"));
+    }
+
+    #[test]
+    fn test_raw_string() {
+        let tokens = tokenize(r#"r"C:\path\n${x}" r"#).unwrap();
+        assert!(matches!(tokens[0].token_type(), TokenType::String));
+        assert_eq!(
+            *tokens[0].literal(),
+            Some(Object::String(r"C:\path\n${x}".to_string()))
+        );
+        // a lone `r` is still an identifier
+        assert!(matches!(tokens[1].token_type(), TokenType::Var));
+    }
+
+    #[test]
+    fn test_multiline_string_strips_indentation() {
+        let input = "var s = \"\"\"\n    first\n      second \"quoted\"\\t\n    \"\"\";\nx";
+        let tokens = tokenize(input).unwrap();
+        assert!(matches!(tokens[3].token_type(), TokenType::String));
+        assert_eq!(
+            *tokens[3].literal(),
+            Some(Object::String("first\n  second \"quoted\"\t".to_string()))
+        );
+        // line numbers continue after the multi-line token
+        assert_eq!(tokens[5].lexeme(), "x");
+        assert_eq!(tokens[5].line(), 5);
+        assert!(tokenize("\"\"\"never closed\"\"").is_err());
+        assert_eq!(
+            *tokenize("\"\"").unwrap()[0].literal(),
+            Some(Object::String(String::new()))
+        );
+    }
+
+    #[test]
+    fn test_multiline_string_keeps_non_ascii_whitespace() {
+        // the no-break space is content, not indentation
+        let input = "\"\"\"\n    a\n  \u{a0}  b\n\"\"\"";
+        let tokens = tokenize(input).unwrap();
+        assert_eq!(
+            *tokens[0].literal(),
+            Some(Object::String("  a\n\u{a0}  b".to_string()))
+        );
+    }
+
     #[test]
     fn test_number_literals() {
         let input = "123 45.67 0 9.0";
@@ -707,6 +1655,39 @@ mod tests {
         assert_eq!(tokens[2].lexeme(), "0");
         assert!(matches!(tokens[3].token_type(), TokenType::Number));
         assert_eq!(tokens[3].lexeme(), "9.0");
+        assert!(matches!(tokens[0].literal(), Some(Object::Integer(123))));
+        assert!(matches!(tokens[1].literal(), Some(Object::Number(_))));
+        assert!(matches!(tokens[3].literal(), Some(Object::Number(_))));
+    }
+
+    #[test]
+    fn test_number_literal_formats() {
+        let literal = |input: &str| tokenize(input).unwrap()[0].literal().clone();
+        assert_eq!(literal("0xFF"), Some(Object::Integer(255)));
+        assert_eq!(literal("0b1010"), Some(Object::Integer(10)));
+        assert_eq!(literal("0o755"), Some(Object::Integer(493)));
+        assert_eq!(literal("1_000_000"), Some(Object::Integer(1_000_000)));
+        assert_eq!(literal("0xFF_FF"), Some(Object::Integer(0xFFFF)));
+        assert_eq!(literal("1e-9"), Some(Object::Number(1e-9)));
+        assert_eq!(literal("6.02E23"), Some(Object::Number(6.02e23)));
+        assert_eq!(literal("2e+3"), Some(Object::Number(2000.0)));
+        assert_eq!(tokenize("0xFF").unwrap()[0].lexeme(), "0xFF");
+    }
+
+    #[test]
+    fn test_malformed_number_literals() {
+        for input in [
+            "1.2.3", "0x", "0b102", "0o8", "1__0", "1_", "1_.5", "1e", "12abc",
+        ] {
+            assert!(tokenize(input).is_err(), "{} should not scan", input);
+        }
+        assert!(tokenize("0x8000000000000000").is_err());
+    }
+
+    #[test]
+    fn test_integer_literal_out_of_range() {
+        assert!(tokenize("9223372036854775807").is_ok());
+        assert!(tokenize("9223372036854775808").is_err());
     }
 
     #[test]
@@ -895,6 +1876,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_private_name() {
+        let tokens = tokenize("this.#secret_1").unwrap();
+        assert_eq!(tokens.len(), 4); // +1 for EOF
+        assert!(matches!(tokens[2].token_type(), TokenType::PrivateName));
+        assert_eq!(tokens[2].lexeme(), "#secret_1");
+
+        assert!(tokenize("this.#").is_err());
+        assert!(tokenize("#1").is_err());
+    }
+
     #[test]
     fn test_unexpected_identifier() {
         let input = "@invalid";
@@ -19,6 +19,10 @@ pub enum Stmt {
     If(IfStmt),
     /// Represents a while loop statement.
     While(WhileStmt),
+    /// Represents a C-style for loop statement.
+    For(ForStmt),
+    /// Represents a for-in loop statement.
+    ForIn(ForInStmt),
     /// Represents a function declaration statement.
     Function(FunctionStmt),
     /// Represents a return statement.
@@ -26,6 +30,16 @@ pub enum Stmt {
     Break,
     Continue,
     Class(ClassStmt),
+    /// Represents a trait declaration.
+    Trait(TraitStmt),
+    /// Represents an import statement.
+    Import(ImportStmt),
+    /// Represents a try statement with its catch and finally clauses.
+    Try(TryStmt),
+    /// Represents a throw statement.
+    Throw(ThrowStmt),
+    /// Represents a match statement.
+    Match(MatchStmt),
 }
 
 /// Defines the visitor trait for calling the `Stmt` type.
@@ -48,6 +62,10 @@ pub trait StmtVisitor<T> {
     fn visit_if_stmt(&mut self, stmt: &mut IfStmt) -> Result<T, RuntimeError>;
     /// Visits a while loop statement.
     fn visit_while_stmt(&mut self, stmt: &mut WhileStmt) -> Result<T, RuntimeError>;
+    /// Visits a C-style for loop statement.
+    fn visit_for_stmt(&mut self, stmt: &mut ForStmt) -> Result<T, RuntimeError>;
+    /// Visits a for-in loop statement.
+    fn visit_for_in_stmt(&mut self, stmt: &mut ForInStmt) -> Result<T, RuntimeError>;
     /// Visits a function declaration statement.
     fn visit_function_stmt(&mut self, stmt: &mut FunctionStmt) -> Result<T, RuntimeError>;
     /// Visits a return statement.
@@ -55,6 +73,16 @@ pub trait StmtVisitor<T> {
     fn visit_break_stmt(&mut self) -> Result<T, RuntimeError>;
     fn visit_continue_stmt(&mut self) -> Result<T, RuntimeError>;
     fn visit_class_stmt(&mut self, stmt: &mut ClassStmt) -> Result<T, RuntimeError>;
+    /// Visits a trait declaration.
+    fn visit_trait_stmt(&mut self, stmt: &mut TraitStmt) -> Result<T, RuntimeError>;
+    /// Visits an import statement.
+    fn visit_import_stmt(&mut self, stmt: &mut ImportStmt) -> Result<T, RuntimeError>;
+    /// Visits a try statement.
+    fn visit_try_stmt(&mut self, stmt: &mut TryStmt) -> Result<T, RuntimeError>;
+    /// Visits a throw statement.
+    fn visit_throw_stmt(&mut self, stmt: &mut ThrowStmt) -> Result<T, RuntimeError>;
+    /// Visits a match statement.
+    fn visit_match_stmt(&mut self, stmt: &mut MatchStmt) -> Result<T, RuntimeError>;
 }
 
 impl Stmt {
@@ -67,11 +95,18 @@ impl Stmt {
             Stmt::Block(block_stmt) => visitor.visit_block_stmt(block_stmt),
             Stmt::If(if_stmt) => visitor.visit_if_stmt(if_stmt),
             Stmt::While(while_stmt) => visitor.visit_while_stmt(while_stmt),
+            Stmt::For(for_stmt) => visitor.visit_for_stmt(for_stmt),
+            Stmt::ForIn(for_in_stmt) => visitor.visit_for_in_stmt(for_in_stmt),
             Stmt::Function(function_stmt) => visitor.visit_function_stmt(function_stmt),
             Stmt::Return(return_stmt) => visitor.visit_return_stmt(return_stmt),
             Stmt::Break => visitor.visit_break_stmt(),
             Stmt::Continue => visitor.visit_continue_stmt(),
             Stmt::Class(class_stmt) => visitor.visit_class_stmt(class_stmt),
+            Stmt::Trait(trait_stmt) => visitor.visit_trait_stmt(trait_stmt),
+            Stmt::Import(import_stmt) => visitor.visit_import_stmt(import_stmt),
+            Stmt::Try(try_stmt) => visitor.visit_try_stmt(try_stmt),
+            Stmt::Throw(throw_stmt) => visitor.visit_throw_stmt(throw_stmt),
+            Stmt::Match(match_stmt) => visitor.visit_match_stmt(match_stmt),
         }
     }
 }
@@ -85,6 +120,8 @@ pub struct VarStmt {
     pub name: Token,
     /// An optional expression that provides the initial value for the variable.
     pub initializer: Option<Expr>,
+    /// Whether the variable was declared with `const` and can't be reassigned.
+    pub is_const: bool,
 }
 
 /// Represents an 'if' statement in the language.
@@ -113,6 +150,39 @@ pub struct WhileStmt {
     pub body: Box<Stmt>,
 }
 
+/// Represents a `for (initializer; condition; increment)` loop statement.
+///
+/// Every iteration runs in its own copy of the loop variables, so closures created in
+/// the body capture the values of their iteration. The increment also runs after a
+/// `continue`.
+#[derive(Debug, Clone)]
+pub struct ForStmt {
+    /// The variable declaration or expression statement run before the loop, if any.
+    pub initializer: Option<Box<Stmt>>,
+    /// The loop condition; the loop runs until it is falsey, or forever if it is missing.
+    pub condition: Option<Expr>,
+    /// The expression evaluated after every iteration, if any.
+    pub increment: Option<Expr>,
+    /// The statement executed on every iteration.
+    pub body: Box<Stmt>,
+}
+
+/// Represents a `for (name in iterable)` loop statement.
+///
+/// Iterates over the characters of a string, the elements of a list, the keys of a map,
+/// the integers of a range, or the values produced by an iterator object.
+#[derive(Debug, Clone)]
+pub struct ForInStmt {
+    /// The 'for' keyword token.
+    pub keyword: Token,
+    /// The loop variable, bound to a fresh variable on every iteration.
+    pub name: Token,
+    /// The expression that produces the value to iterate over.
+    pub iterable: Expr,
+    /// The statement executed for every value.
+    pub body: Box<Stmt>,
+}
+
 /// Represents a function declaration statement.
 ///
 /// Contains the function's name, its parameter list, and its body statements.
@@ -121,11 +191,26 @@ pub struct FunctionStmt {
     /// The name of the function.
     pub name: Token,
     /// The parameters of the function.
-    pub params: Vec<Token>,
+    pub params: Vec<Param>,
     /// The body of the function as a list of statements.
     pub body: Vec<Stmt>,
     /// Whether this function is static.
     pub is_static: bool,
+    /// Whether this function is a `get name()` accessor of a class.
+    pub is_getter: bool,
+    /// Whether this function is a `set name(value)` accessor of a class.
+    pub is_setter: bool,
+}
+
+/// Represents a parameter of a function, e.g. `a`, `b = 2` or `...rest`.
+#[derive(Debug, Clone)]
+pub struct Param {
+    /// The name of the parameter.
+    pub name: Token,
+    /// The value used when no argument is passed for the parameter, if any.
+    pub default: Option<Expr>,
+    /// Whether the parameter collects all remaining positional arguments into a list.
+    pub variadic: bool,
 }
 
 /// Represents a return statement in a function.
@@ -143,5 +228,146 @@ pub struct ReturnStmt {
 pub struct ClassStmt {
     pub name: Token,
     pub superclass: Option<VariableExpr>,
+    /// The traits listed after `with`, whose methods are merged into the class.
+    pub traits: Vec<VariableExpr>,
+    pub methods: Vec<FunctionStmt>,
+    /// The `static var` declarations of the class body.
+    pub static_fields: Vec<VarStmt>,
+}
+
+/// Represents a trait declaration, e.g. `trait Named { fn name(); fn greet() { ... } }`.
+///
+/// A trait is a set of methods that classes list after `with` to have them merged into
+/// their own methods.
+#[derive(Debug, Clone)]
+pub struct TraitStmt {
+    /// The name of the trait.
+    pub name: Token,
+    /// The methods the trait provides.
     pub methods: Vec<FunctionStmt>,
+    /// The methods declared without a body, which a class using the trait must provide.
+    pub required: Vec<Token>,
+}
+
+/// Represents an import statement, e.g. `import "lib/utils.bl" as utils;`.
+///
+/// Loads the file at `path` as a module and binds it to `name`.
+#[derive(Debug, Clone)]
+pub struct ImportStmt {
+    /// The 'import' keyword token.
+    pub keyword: Token,
+    /// The string literal token holding the path of the imported file.
+    pub path: Token,
+    /// The name the module is bound to, either given with `as` or taken from the file name.
+    pub name: Token,
+}
+
+/// Represents a `try` statement.
+///
+/// At least one of `catch` and `finally` is present. The `finally` block runs no matter
+/// how the `try` and `catch` blocks were left.
+#[derive(Debug, Clone)]
+pub struct TryStmt {
+    /// The statements of the try block.
+    pub body: Vec<Stmt>,
+    /// The catch clause, if any.
+    pub catch: Option<CatchClause>,
+    /// The statements of the finally block, if any.
+    pub finally: Option<Vec<Stmt>>,
+}
+
+/// Represents the `catch (name) { ... }` clause of a try statement.
+#[derive(Debug, Clone)]
+pub struct CatchClause {
+    /// The variable the caught error is bound to.
+    pub name: Token,
+    /// The statements of the catch block.
+    pub body: Vec<Stmt>,
+}
+
+/// Represents a `throw` statement.
+#[derive(Debug, Clone)]
+pub struct ThrowStmt {
+    /// The 'throw' keyword token.
+    pub keyword: Token,
+    /// The value that is thrown.
+    pub value: Expr,
+}
+
+/// Represents a `match` statement.
+///
+/// The arms are tried in order and the body of the first one whose pattern matches
+/// (and whose guard, if any, is truthy) is executed.
+#[derive(Debug, Clone)]
+pub struct MatchStmt {
+    /// The 'match' keyword token.
+    pub keyword: Token,
+    /// The value that is matched against the arms.
+    pub value: Expr,
+    /// The arms of the match statement.
+    pub arms: Vec<MatchArm>,
+}
+
+/// Represents a single `pattern if guard => body` arm of a match statement.
+#[derive(Debug, Clone)]
+pub struct MatchArm {
+    /// The pattern the value has to match.
+    pub pattern: Pattern,
+    /// An optional condition that is checked after the pattern matched.
+    pub guard: Option<Expr>,
+    /// The '=>' token, used to report the arm's line.
+    pub arrow: Token,
+    /// The statement executed when the arm is chosen.
+    pub body: Box<Stmt>,
+}
+
+/// Represents a pattern of a match arm.
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    /// Matches values equal to a literal, e.g. `1`, `"a"` or `nil`.
+    Literal(Box<Expr>),
+    /// Matches anything, written `_`.
+    Wildcard,
+    /// Matches anything and binds the value to a name.
+    Binding(Token),
+    /// Matches if any of the patterns matches, e.g. `1 | 2`.
+    Alternatives(Vec<Pattern>),
+    /// Matches instances of a class, e.g. `Point(x, y: 0)`.
+    Class(ClassPattern),
+}
+
+impl Pattern {
+    /// Returns whether the pattern matches every value.
+    pub fn is_irrefutable(&self) -> bool {
+        match self {
+            Pattern::Wildcard | Pattern::Binding(_) => true,
+            Pattern::Alternatives(patterns) => patterns.iter().any(Pattern::is_irrefutable),
+            Pattern::Literal(_) | Pattern::Class(_) => false,
+        }
+    }
+
+    /// Returns whether matching the pattern binds any variables.
+    pub fn binds_variables(&self) -> bool {
+        match self {
+            Pattern::Binding(_) => true,
+            Pattern::Alternatives(patterns) => patterns.iter().any(Pattern::binds_variables),
+            Pattern::Class(class) => class
+                .fields
+                .iter()
+                .any(|(_, pattern)| pattern.binds_variables()),
+            Pattern::Literal(_) | Pattern::Wildcard => false,
+        }
+    }
+}
+
+/// Represents a class pattern like `Point(x, y: 0)`.
+///
+/// A field written on its own binds the field to a variable of the same name, while
+/// `field: pattern` matches the field against another pattern.
+#[derive(Debug, Clone)]
+pub struct ClassPattern {
+    /// The expression naming the class.
+    pub class: VariableExpr,
+    /// The fields of the instance and the patterns they have to match.
+    pub fields: Vec<(Token, Pattern)>,
 }
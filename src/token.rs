@@ -4,37 +4,63 @@ use std::fmt;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TokenType {
     // Single-character tokens
-    LeftParen,  // (
-    RightParen, // )
-    LeftBrace,  // {
-    RightBrace, // }
-    Comma,      // ,
-    Dot,        // .
-    Minus,      // -
-    Plus,       // +
-    Semicolon,  // ;
-    Asterisk,   // *
+    LeftParen,    // (
+    RightParen,   // )
+    LeftBrace,    // {
+    RightBrace,   // }
+    LeftBracket,  // [
+    RightBracket, // ]
+    Comma,        // ,
+    Dot,          // .
+    Minus,        // -
+    Plus,         // +
+    Semicolon,    // ;
+    Colon,        // :
+    Question,     // ?
+    Asterisk,     // *
+    Percent,      // %
 
     // One or two character tokens
-    BangEqual,    // !=
-    Bang,         // !
-    EqualEqual,   // ==
-    Equal,        // =
-    LessEqual,    // <=
-    Less,         // <
-    GreaterEqual, // >=
-    Greater,      // >
-    Slash,        // /
+    BangEqual,        // !=
+    Bang,             // !
+    EqualEqual,       // ==
+    Equal,            // =
+    LessEqual,        // <=
+    Less,             // <
+    GreaterEqual,     // >=
+    Greater,          // >
+    Slash,            // /
+    StarStar,         // **
+    TildeSlash,       // ~/
+    PlusEqual,        // +=
+    MinusEqual,       // -=
+    StarEqual,        // *=
+    SlashEqual,       // /=
+    PercentEqual,     // %=
+    PlusPlus,         // ++
+    MinusMinus,       // --
+    QuestionQuestion, // ??
+    QuestionDot,      // ?.
+    DotDot,           // ..
+    DotDotEqual,      // ..=
+    Ellipsis,         // ...
+    FatArrow,         // =>
 
     // Logical and Bitwise Operators
     And,        // &&
     Or,         // ||
     BitwiseAnd, // &
     BitwiseOr,  // |
+    BitwiseXor, // ^
+    BitwiseNot, // ~
+    ShiftLeft,  // <<
+    ShiftRight, // >>
 
     // Literals
-    String, // "..."
-    Number, // 123, 123.45
+    String,           // "..."
+    Interpolation,    // "...${, a string segment followed by an embedded expression
+    InterpolationEnd, // }, closing an embedded expression
+    Number,           // 123, 123.45
 
     // Keywords
     Else,
@@ -49,14 +75,28 @@ pub enum TokenType {
     This,
     True,
     VarKeyword, // Represents the 'var' keyword specifically when used for declaration
+    Const,
     While,
     Break,
     Continue,
     Class,
     Static,
+    Import,
+    As,
+    In,
+    Try,
+    Catch,
+    Finally,
+    Throw,
+    Match,
+    Is,
+    Trait,
+    With,
+    Instanceof,
 
     // Identifier
-    Var, // Represents a variable or identifier name
+    Var,         // Represents a variable or identifier name
+    PrivateName, // #name, a private field or method of a class
 
     // End of file
     Eof,
@@ -0,0 +1,33 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::function::Function;
+
+/// Represents a trait, created by a `trait` declaration.
+///
+/// A trait can't be instantiated; classes list it after `with` and get its methods
+/// copied into their own method table.
+#[derive(Debug, Clone)]
+pub struct Trait {
+    pub name: String,
+    /// The methods the trait provides, closing over the scope the trait was declared in.
+    pub methods: HashMap<String, Function>,
+    /// The names of the methods a class using the trait has to provide.
+    pub required: Vec<String>,
+}
+
+impl Trait {
+    pub fn new(name: String, methods: HashMap<String, Function>, required: Vec<String>) -> Self {
+        Trait {
+            name,
+            methods,
+            required,
+        }
+    }
+}
+
+impl Display for Trait {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<trait {}>", self.name)
+    }
+}